@@ -0,0 +1,87 @@
+use std::f64::consts::PI;
+use std::rc::Rc;
+use trtc::Pattern;
+
+fn main() {
+    let mut checkers = trtc::CheckersPattern::new(
+        trtc::Color::new(1.0, 1.0, 1.0),
+        trtc::Color::new(0.2, 0.2, 0.2),
+    );
+    checkers.set_transform(trtc::rotation_y(PI / 6.0));
+
+    let mut floor = trtc::Plane::default();
+    floor.material.pattern = Some(Rc::new(checkers));
+    floor.material.specular = 0.0;
+
+    let mut rings = trtc::RingPattern::new(
+        trtc::Color::new(0.9, 0.9, 0.6),
+        trtc::Color::new(0.6, 0.4, 0.2),
+    );
+    rings.set_transform(trtc::scaling(0.5, 0.5, 0.5));
+
+    let mut backdrop = trtc::Plane::new(
+        trtc::Matrix4::eye()
+            .rotate_x(PI / 2.0)
+            .translate(0.0, 0.0, 10.0),
+    );
+    backdrop.material.pattern = Some(Rc::new(rings));
+    backdrop.material.specular = 0.0;
+
+    let mut stripes = trtc::StripePattern::new(
+        trtc::Color::new(0.1, 1.0, 0.5),
+        trtc::Color::new(0.1, 0.5, 1.0),
+    );
+    stripes.set_transform(
+        trtc::Matrix4::eye()
+            .scale(0.25, 0.25, 0.25)
+            .rotate_z(PI / 4.0),
+    );
+
+    let mut middle = trtc::Sphere::new(trtc::translation(-0.5, 1.0, 0.5));
+    middle.material.pattern = Some(Rc::new(stripes));
+    middle.material.diffuse = 0.7;
+    middle.material.specular = 0.3;
+
+    let mut gradient = trtc::GradientPattern::new(
+        trtc::Color::new(1.0, 0.8, 0.1),
+        trtc::Color::new(1.0, 0.2, 0.1),
+    );
+    gradient.set_transform(
+        trtc::Matrix4::eye()
+            .scale(2.0, 1.0, 1.0)
+            .translate(-1.0, 0.0, 0.0),
+    );
+
+    let mut right = trtc::Sphere::new(
+        trtc::Matrix4::eye()
+            .scale(0.5, 0.5, 0.5)
+            .translate(1.5, 0.5, -0.5),
+    );
+    right.material.pattern = Some(Rc::new(gradient));
+    right.material.diffuse = 0.7;
+    right.material.specular = 0.3;
+
+    let world = trtc::World {
+        objects: vec![
+            Box::new(floor),
+            Box::new(backdrop),
+            Box::new(middle),
+            Box::new(right),
+        ],
+        lights: vec![Box::new(trtc::PointLight::new(
+            trtc::point(-10.0, 10.0, -10.0),
+            trtc::Color::new(1.0, 1.0, 1.0),
+        ))],
+    };
+
+    let mut camera = trtc::Camera::new(200, 100, PI / 3.0);
+    camera.transform = trtc::view_transform(
+        trtc::point(0.0, 1.5, -5.0),
+        trtc::point(0.0, 1.0, 0.0),
+        trtc::vector(0.0, 1.0, 0.0),
+    );
+
+    trtc::render(&camera, &world)
+        .save_png("out.png".into())
+        .expect("Failed to save png");
+}
@@ -0,0 +1,67 @@
+use std::f64::consts::PI;
+
+fn hexagon_corner() -> trtc::Sphere {
+    trtc::Sphere::new(
+        trtc::Matrix4::eye()
+            .scale(0.25, 0.25, 0.25)
+            .translate(0.0, 0.0, -1.0),
+    )
+}
+
+fn hexagon_edge() -> trtc::Cylinder {
+    trtc::Cylinder {
+        minimum: 0.0,
+        maximum: 1.0,
+        ..trtc::Cylinder::new(
+            trtc::Matrix4::eye()
+                .scale(0.25, 1.0, 0.25)
+                .rotate_z(-PI / 2.0)
+                .rotate_y(-PI / 6.0)
+                .translate(0.0, 0.0, -1.0),
+        )
+    }
+}
+
+fn hexagon_side(n: usize) -> trtc::Group {
+    let mut side = trtc::Group::new(trtc::rotation_y(n as f64 * PI / 3.0));
+    side.add_child(Box::new(hexagon_corner()));
+    side.add_child(Box::new(hexagon_edge()));
+    side
+}
+
+fn hexagon() -> trtc::Group {
+    let mut hex = trtc::Group::new(
+        trtc::Matrix4::eye()
+            .rotate_x(-PI / 6.0)
+            .translate(0.0, 1.0, 0.0),
+    );
+    for n in 0..6 {
+        hex.add_child(Box::new(hexagon_side(n)));
+    }
+    hex
+}
+
+fn main() {
+    let mut floor = trtc::Plane::default();
+    floor.material.color = trtc::Color::new(1.0, 0.9, 0.9);
+    floor.material.specular = 0.0;
+
+    let world = trtc::World {
+        objects: vec![Box::new(floor), Box::new(hexagon())],
+        lights: vec![Box::new(trtc::PointLight::new(
+            trtc::point(-10.0, 10.0, -10.0),
+            trtc::Color::new(1.0, 1.0, 1.0),
+        ))],
+    };
+
+    let mut camera = trtc::Camera::new(200, 100, PI / 3.0);
+    camera.transform = trtc::view_transform(
+        trtc::point(0.0, 1.5, -5.0),
+        trtc::point(0.0, 1.0, 0.0),
+        trtc::vector(0.0, 1.0, 0.0),
+    );
+
+    trtc::render(&camera, &world)
+        .save_png("out.png".into())
+        .expect("Failed to save png");
+}
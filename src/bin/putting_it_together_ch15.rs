@@ -0,0 +1,35 @@
+use std::f64::consts::PI;
+use trtc::Shape;
+
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .expect("Usage: putting_it_together_ch15 <model.obj>");
+    let obj = trtc::load_obj_file(path.into()).expect("Failed to load obj file");
+
+    let mut model = obj.to_group();
+    model.divide(8);
+
+    let mut floor = trtc::Plane::new(trtc::translation(0.0, -1.0, 0.0));
+    floor.material.color = trtc::Color::new(1.0, 0.9, 0.9);
+    floor.material.specular = 0.0;
+
+    let world = trtc::World {
+        objects: vec![Box::new(floor), Box::new(model)],
+        lights: vec![Box::new(trtc::PointLight::new(
+            trtc::point(-10.0, 10.0, -10.0),
+            trtc::Color::new(1.0, 1.0, 1.0),
+        ))],
+    };
+
+    let mut camera = trtc::Camera::new(200, 100, PI / 3.0);
+    camera.transform = trtc::view_transform(
+        trtc::point(0.0, 1.5, -5.0),
+        trtc::point(0.0, 0.0, 0.0),
+        trtc::vector(0.0, 1.0, 0.0),
+    );
+
+    trtc::render(&camera, &world)
+        .save_png("out.png".into())
+        .expect("Failed to save png");
+}
@@ -1,5 +1,3 @@
-use trtc;
-
 struct Environment {
     gravity: trtc::Tuple4,
     wind: trtc::Tuple4,
@@ -1,5 +1,3 @@
-use trtc;
-
 fn main() {
     let mut canvas = trtc::Canvas::new(512, 512);
 
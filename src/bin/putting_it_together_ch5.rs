@@ -1,30 +1,40 @@
+use trtc::Sampler;
+
 fn main() {
     let mut canvas = trtc::Canvas::new(200, 200);
-    let mut shape = trtc::Sphere::default();
-
-    shape.transform = trtc::Matrix4::eye()
-        .scale(0.5, 1.0, 1.0)
-        .shear(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    let shape = trtc::Sphere::new(
+        trtc::Matrix4::eye()
+            .scale(0.5, 1.0, 1.0)
+            .shear(1.0, 0.0, 0.0, 0.0, 0.0, 0.0),
+    );
 
     let ray_origin = trtc::point(0.0, 0.0, -5.0);
     let wall_z = 10.0;
     let wall_size = 7.0;
     let pixel_size = wall_size / canvas.width as f64;
+    let sampler = trtc::StratifiedSampler::new(16, 0);
 
     for y in 0..canvas.height {
-        let world_y = (wall_size / 2.0) - pixel_size * y as f64;
-
         for x in 0..canvas.height {
-            let world_x = -(wall_size / 2.0) + pixel_size * x as f64;
+            let samples = sampler.samples(x, y);
+            let mut hits = 0;
 
-            let position = trtc::point(world_x, world_y, wall_z);
+            for &(dx, dy) in &samples {
+                let world_y = (wall_size / 2.0) - pixel_size * (y as f64 + dy);
+                let world_x = -(wall_size / 2.0) + pixel_size * (x as f64 + dx);
 
-            let r = trtc::Ray::new(ray_origin, (position - ray_origin).normalize());
-            let xs = trtc::intersect(&shape, &r);
+                let position = trtc::point(world_x, world_y, wall_z);
 
-            if trtc::hit(&xs).is_some() {
-                canvas[(x, y)] = trtc::Color::new(1.0, 0.0, 0.0);
+                let r = trtc::Ray::new(ray_origin, (position - ray_origin).normalize());
+                let xs = trtc::intersect(&shape, &r);
+
+                if trtc::hit(&xs).is_some() {
+                    hits += 1;
+                }
             }
+
+            let coverage = hits as f64 / samples.len() as f64;
+            canvas[(x, y)] = trtc::Color::new(1.0, 0.0, 0.0) * coverage;
         }
     }
 
@@ -0,0 +1,48 @@
+fn main() {
+    let mut canvas = trtc::Canvas::new(200, 200);
+    let mut shape = trtc::Sphere::default();
+    shape.material.color = trtc::Color::new(1.0, 0.2, 1.0);
+
+    let light = trtc::PointLight::new(
+        trtc::point(-10.0, 10.0, -10.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    let ray_origin = trtc::point(0.0, 0.0, -5.0);
+    let wall_z = 10.0;
+    let wall_size = 7.0;
+    let pixel_size = wall_size / canvas.width as f64;
+
+    for y in 0..canvas.height {
+        let world_y = (wall_size / 2.0) - pixel_size * y as f64;
+
+        for x in 0..canvas.width {
+            let world_x = -(wall_size / 2.0) + pixel_size * x as f64;
+
+            let position = trtc::point(world_x, world_y, wall_z);
+
+            let r = trtc::Ray::new(ray_origin, (position - ray_origin).normalize());
+            let xs = trtc::intersect(&shape, &r);
+
+            if let Some(hit) = trtc::hit(&xs) {
+                let point = trtc::position(&r, hit.t);
+                let normal = trtc::normal_at(hit.object, point, hit);
+                let eye = -r.direction;
+
+                canvas[(x, y)] = trtc::lighting(
+                    hit.object.material(),
+                    hit.object,
+                    &light,
+                    point,
+                    eye,
+                    normal,
+                    1.0,
+                );
+            }
+        }
+    }
+
+    canvas
+        .save_png("out.png".into())
+        .expect("Failed to save png");
+}
@@ -0,0 +1,62 @@
+use std::f64::consts::PI;
+
+fn main() {
+    let mut floor = trtc::Plane::default();
+    floor.material.color = trtc::Color::new(1.0, 0.9, 0.9);
+    floor.material.specular = 0.0;
+
+    let mut backdrop = trtc::Plane::new(
+        trtc::Matrix4::eye()
+            .rotate_x(PI / 2.0)
+            .translate(0.0, 0.0, 10.0),
+    );
+    backdrop.material = floor.material.clone();
+
+    let mut middle = trtc::Sphere::new(trtc::translation(-0.5, 1.0, 0.5));
+    middle.material.color = trtc::Color::new(0.1, 1.0, 0.5);
+    middle.material.diffuse = 0.7;
+    middle.material.specular = 0.3;
+
+    let mut right = trtc::Sphere::new(
+        trtc::Matrix4::eye()
+            .scale(0.5, 0.5, 0.5)
+            .translate(1.5, 0.5, -0.5),
+    );
+    right.material.color = trtc::Color::new(0.5, 1.0, 0.1);
+    right.material.diffuse = 0.7;
+    right.material.specular = 0.3;
+
+    let mut left = trtc::Sphere::new(
+        trtc::Matrix4::eye()
+            .scale(0.33, 0.33, 0.33)
+            .translate(-1.5, 0.33, -0.75),
+    );
+    left.material.color = trtc::Color::new(1.0, 0.8, 0.1);
+    left.material.diffuse = 0.7;
+    left.material.specular = 0.3;
+
+    let world = trtc::World {
+        objects: vec![
+            Box::new(floor),
+            Box::new(backdrop),
+            Box::new(middle),
+            Box::new(right),
+            Box::new(left),
+        ],
+        lights: vec![Box::new(trtc::PointLight::new(
+            trtc::point(-10.0, 10.0, -10.0),
+            trtc::Color::new(1.0, 1.0, 1.0),
+        ))],
+    };
+
+    let mut camera = trtc::Camera::new(200, 100, PI / 3.0);
+    camera.transform = trtc::view_transform(
+        trtc::point(0.0, 1.5, -5.0),
+        trtc::point(0.0, 1.0, 0.0),
+        trtc::vector(0.0, 1.0, 0.0),
+    );
+
+    trtc::render(&camera, &world)
+        .save_png("out.png".into())
+        .expect("Failed to save png");
+}
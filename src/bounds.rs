@@ -0,0 +1,130 @@
+use crate::utils::EPSILON;
+use crate::{Matrix4, Ray, Tuple4, point};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min: Tuple4,
+    pub max: Tuple4,
+}
+
+impl BoundingBox {
+    pub fn new(min: Tuple4, max: Tuple4) -> Self {
+        Self { min, max }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn is_finite(&self) -> bool {
+        [self.min, self.max]
+            .iter()
+            .all(|p| p.x.is_finite() && p.y.is_finite() && p.z.is_finite())
+    }
+
+    pub fn add_point(&mut self, p: Tuple4) {
+        self.min = point(
+            self.min.x.min(p.x),
+            self.min.y.min(p.y),
+            self.min.z.min(p.z),
+        );
+        self.max = point(
+            self.max.x.max(p.x),
+            self.max.y.max(p.y),
+            self.max.z.max(p.z),
+        );
+    }
+
+    pub fn add_box(&mut self, other: &BoundingBox) {
+        if !other.is_empty() {
+            self.add_point(other.min);
+            self.add_point(other.max);
+        }
+    }
+
+    pub fn contains_point(&self, p: Tuple4) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x)
+            && (self.min.y..=self.max.y).contains(&p.y)
+            && (self.min.z..=self.max.z).contains(&p.z)
+    }
+
+    pub fn contains_box(&self, other: &BoundingBox) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    pub fn centroid(&self) -> Tuple4 {
+        point(
+            (self.min.x + self.max.x) / 2.0,
+            (self.min.y + self.max.y) / 2.0,
+            (self.min.z + self.max.z) / 2.0,
+        )
+    }
+
+    pub fn surface_area(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        let d = self.max - self.min;
+        2.0 * (d.x * d.y + d.y * d.z + d.z * d.x)
+    }
+
+    pub fn transform(&self, m: &Matrix4) -> Self {
+        if self.is_empty() {
+            return *self;
+        }
+
+        let mut min = point(0.0, 0.0, 0.0);
+        let mut max = point(0.0, 0.0, 0.0);
+        for i in 0..3 {
+            min[i] = m[(i, 3)];
+            max[i] = m[(i, 3)];
+            for j in 0..3 {
+                if m[(i, j)] == 0.0 {
+                    continue;
+                }
+
+                let a = m[(i, j)] * self.min[j];
+                let b = m[(i, j)] * self.max[j];
+                min[i] += a.min(b);
+                max[i] += a.max(b);
+            }
+        }
+        Self::new(min, max)
+    }
+
+    pub fn intersects(&self, ray: &Ray) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let mut tmin = f64::NEG_INFINITY;
+        let mut tmax = f64::INFINITY;
+
+        for i in 0..3 {
+            let (origin, direction) = (ray.origin[i], ray.direction[i]);
+            if direction.abs() < EPSILON {
+                if origin < self.min[i] || origin > self.max[i] {
+                    return false;
+                }
+                continue;
+            }
+
+            let t0 = (self.min[i] - origin) / direction;
+            let t1 = (self.max[i] - origin) / direction;
+            tmin = tmin.max(t0.min(t1));
+            tmax = tmax.min(t0.max(t1));
+        }
+
+        tmin <= tmax
+    }
+}
+
+impl Default for BoundingBox {
+    fn default() -> Self {
+        Self::new(
+            point(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            point(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+        )
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{Perlin, Tuple4, UvMapping, UvPattern, vector};
+use std::fmt::Debug;
+
+const DELTA: f64 = 0.0001;
+
+pub trait NormalMap: Debug {
+    fn perturb_normal(&self, object_point: Tuple4, normal: Tuple4) -> Tuple4;
+}
+
+pub trait HeightField: Debug {
+    fn height_at(&self, object_point: Tuple4) -> f64;
+}
+
+impl HeightField for Perlin {
+    fn height_at(&self, object_point: Tuple4) -> f64 {
+        self.noise(object_point)
+    }
+}
+
+impl HeightField for fn(Tuple4) -> f64 {
+    fn height_at(&self, object_point: Tuple4) -> f64 {
+        self(object_point)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BumpMap<H> {
+    pub height: H,
+    pub scale: f64,
+}
+
+impl<H> BumpMap<H> {
+    pub fn new(height: H, scale: f64) -> Self {
+        Self { height, scale }
+    }
+}
+
+impl<H: HeightField> NormalMap for BumpMap<H> {
+    fn perturb_normal(&self, object_point: Tuple4, normal: Tuple4) -> Tuple4 {
+        let slope = |axis: Tuple4| {
+            let ahead = self.height.height_at(object_point + axis * DELTA);
+            let behind = self.height.height_at(object_point - axis * DELTA);
+            (ahead - behind) / (2.0 * DELTA)
+        };
+        let gradient = vector(
+            slope(vector(1.0, 0.0, 0.0)),
+            slope(vector(0.0, 1.0, 0.0)),
+            slope(vector(0.0, 0.0, 1.0)),
+        );
+
+        let surface_gradient = gradient - normal * gradient.dot(&normal);
+        (normal - surface_gradient * self.scale).normalize()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NormalMapTexture<P> {
+    pub uv_pattern: P,
+    pub mapping: UvMapping,
+}
+
+impl<P> NormalMapTexture<P> {
+    pub fn new(uv_pattern: P, mapping: UvMapping) -> Self {
+        Self {
+            uv_pattern,
+            mapping,
+        }
+    }
+}
+
+fn uv_slope(mapping: UvMapping, object_point: Tuple4, axis: Tuple4) -> (f64, f64) {
+    let wrapped = |d: f64| d - d.round();
+    let (u0, v0) = mapping.map(object_point - axis * DELTA);
+    let (u1, v1) = mapping.map(object_point + axis * DELTA);
+    (
+        wrapped(u1 - u0) / (2.0 * DELTA),
+        wrapped(v1 - v0) / (2.0 * DELTA),
+    )
+}
+
+fn tangent_frame(mapping: UvMapping, object_point: Tuple4, normal: Tuple4) -> (Tuple4, Tuple4) {
+    let helper = if normal.x.abs() < 0.9 {
+        vector(1.0, 0.0, 0.0)
+    } else {
+        vector(0.0, 1.0, 0.0)
+    };
+    let s = helper.cross(&normal).normalize();
+    let t = normal.cross(&s);
+
+    let (du_s, dv_s) = uv_slope(mapping, object_point, s);
+    let (du_t, dv_t) = uv_slope(mapping, object_point, t);
+
+    let mut tangent = s * du_s + t * du_t;
+    if tangent.magnitude() == 0.0 {
+        tangent = s;
+    }
+    let tangent = (tangent - normal * tangent.dot(&normal)).normalize();
+
+    let mut bitangent = normal.cross(&tangent);
+    if bitangent.dot(&(s * dv_s + t * dv_t)) < 0.0 {
+        bitangent = -bitangent;
+    }
+
+    (tangent, bitangent)
+}
+
+impl<P: UvPattern> NormalMap for NormalMapTexture<P> {
+    fn perturb_normal(&self, object_point: Tuple4, normal: Tuple4) -> Tuple4 {
+        let (u, v) = self.mapping.map(object_point);
+        let color = self.uv_pattern.uv_pattern_at(u, v);
+        let (tangent, bitangent) = tangent_frame(self.mapping, object_point, normal);
+
+        let perturbed = tangent * (color.r * 2.0 - 1.0)
+            + bitangent * (color.g * 2.0 - 1.0)
+            + normal * (color.b * 2.0 - 1.0);
+        perturbed.normalize()
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Canvas, Color, Matrix4, Ray, Sampler, UniformSampler, World, color_at, point};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+pub const MAX_ADAPTIVE_DEPTH: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveSampling {
+    pub threshold: f64,
+    pub max_depth: usize,
+}
+
+impl AdaptiveSampling {
+    pub fn new(threshold: f64, max_depth: usize) -> Self {
+        Self {
+            threshold,
+            max_depth: max_depth.min(MAX_ADAPTIVE_DEPTH),
+        }
+    }
+
+    fn resolution(&self) -> usize {
+        1 << self.max_depth.min(MAX_ADAPTIVE_DEPTH)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Camera {
+    pub hsize: usize,
+    pub vsize: usize,
+    pub field_of_view: f64,
+    pub transform: Matrix4,
+    pub max_depth: usize,
+    pub exposure: f64,
+    pub sampler: Rc<dyn Sampler>,
+    pub adaptive: Option<AdaptiveSampling>,
+}
+
+impl Camera {
+    pub fn new(hsize: usize, vsize: usize, field_of_view: f64) -> Self {
+        Self {
+            hsize,
+            vsize,
+            field_of_view,
+            transform: Matrix4::eye(),
+            max_depth: 5,
+            exposure: 1.0,
+            sampler: Rc::new(UniformSampler::default()),
+            adaptive: None,
+        }
+    }
+
+    fn half_extents(&self) -> (f64, f64) {
+        let half_view = (self.field_of_view / 2.0).tan();
+        let aspect = self.hsize as f64 / self.vsize as f64;
+        if aspect >= 1.0 {
+            (half_view, half_view / aspect)
+        } else {
+            (half_view * aspect, half_view)
+        }
+    }
+
+    pub fn pixel_size(&self) -> f64 {
+        let (half_width, _) = self.half_extents();
+        half_width * 2.0 / self.hsize as f64
+    }
+
+    pub fn ray_for_pixel(&self, px: usize, py: usize) -> Ray {
+        self.ray_for_pixel_offset(px, py, 0.5, 0.5)
+    }
+
+    pub fn ray_for_pixel_offset(&self, px: usize, py: usize, dx: f64, dy: f64) -> Ray {
+        let (half_width, half_height) = self.half_extents();
+        let pixel_size = half_width * 2.0 / self.hsize as f64;
+
+        let xoffset = (px as f64 + dx) * pixel_size;
+        let yoffset = (py as f64 + dy) * pixel_size;
+
+        let world_x = half_width - xoffset;
+        let world_y = half_height - yoffset;
+
+        let inverse = self.transform.inverse().unwrap();
+        let pixel = inverse * point(world_x, world_y, -1.0);
+        let origin = inverse * point(0.0, 0.0, 0.0);
+        let direction = (pixel - origin).normalize();
+
+        Ray::new(origin, direction)
+    }
+}
+
+pub fn render(camera: &Camera, world: &World) -> Canvas {
+    let mut image = Canvas::new(camera.hsize, camera.vsize);
+    let mut cache = HashMap::new();
+    for y in 0..camera.vsize {
+        for x in 0..camera.hsize {
+            let color = match &camera.adaptive {
+                Some(adaptive) => sample_adaptively(camera, world, x, y, adaptive, &mut cache),
+                None => pixel_color(camera, world, x, y),
+            };
+            image[(x, y)] = color * camera.exposure;
+        }
+
+        if let Some(adaptive) = &camera.adaptive {
+            let next_row = (y + 1) * adaptive.resolution();
+            cache.retain(|&(_, gy), _| gy >= next_row);
+        }
+    }
+    image
+}
+
+pub fn pixel_color(camera: &Camera, world: &World, px: usize, py: usize) -> Color {
+    if let Some(adaptive) = &camera.adaptive {
+        return adaptive_pixel_color(camera, world, px, py, adaptive);
+    }
+
+    let samples = camera.sampler.samples(px, py);
+    let sum = samples
+        .iter()
+        .map(|&(dx, dy)| {
+            let ray = camera.ray_for_pixel_offset(px, py, dx, dy);
+            color_at(world, &ray, camera.max_depth)
+        })
+        .fold(Color::default(), |acc, c| acc + c);
+    sum * (1.0 / samples.len() as f64)
+}
+
+fn exceeds_threshold(colors: &[Color; 4], threshold: f64) -> bool {
+    let mean = (colors[0] + colors[1] + colors[2] + colors[3]) * 0.25;
+    colors.iter().any(|c| {
+        let d = *c - mean;
+        d.r.abs().max(d.g.abs()).max(d.b.abs()) > threshold
+    })
+}
+
+pub fn adaptive_pixel_color(
+    camera: &Camera,
+    world: &World,
+    px: usize,
+    py: usize,
+    adaptive: &AdaptiveSampling,
+) -> Color {
+    sample_adaptively(camera, world, px, py, adaptive, &mut HashMap::new())
+}
+
+fn sample_adaptively(
+    camera: &Camera,
+    world: &World,
+    px: usize,
+    py: usize,
+    adaptive: &AdaptiveSampling,
+    cache: &mut HashMap<(usize, usize), Color>,
+) -> Color {
+    let resolution = adaptive.resolution();
+    let (x0, y0) = (px * resolution, py * resolution);
+    let mut sample = |gx: usize, gy: usize| {
+        *cache.entry((x0 + gx, y0 + gy)).or_insert_with(|| {
+            let dx = gx as f64 / resolution as f64;
+            let dy = gy as f64 / resolution as f64;
+            let ray = camera.ray_for_pixel_offset(px, py, dx, dy);
+            color_at(world, &ray, camera.max_depth)
+        })
+    };
+
+    let mut stack = vec![(0, 0, resolution)];
+    let mut sum = Color::default();
+    while let Some((gx, gy, size)) = stack.pop() {
+        let corners = [
+            sample(gx, gy),
+            sample(gx + size, gy),
+            sample(gx, gy + size),
+            sample(gx + size, gy + size),
+        ];
+
+        if size > 1 && exceeds_threshold(&corners, adaptive.threshold) {
+            let half = size / 2;
+            stack.push((gx, gy, half));
+            stack.push((gx + half, gy, half));
+            stack.push((gx, gy + half, half));
+            stack.push((gx + half, gy + half, half));
+        } else {
+            let area = (size as f64 / resolution as f64).powi(2);
+            sum = sum + (corners[0] + corners[1] + corners[2] + corners[3]) * (0.25 * area);
+        }
+    }
+    sum
+}
@@ -1,6 +1,6 @@
 use crate::color::Color;
 use std::fs::File;
-use std::io::BufWriter;
+use std::io::{BufReader, BufWriter};
 use std::ops::{Index, IndexMut};
 use std::path::PathBuf;
 
@@ -20,10 +20,39 @@ impl Canvas {
         }
     }
 
-    pub fn save_png(self: &Self, path: PathBuf) -> Result<(), std::io::Error> {
+    pub fn load_png(path: PathBuf) -> Result<Self, std::io::Error> {
+        let file = File::open(path.as_path())?;
+
+        let mut decoder = png::Decoder::new(BufReader::new(file));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info()?;
+
+        let mut data = vec![0; reader.output_buffer_size().unwrap_or(0)];
+        let info = reader.next_frame(&mut data)?;
+        let channels = info.color_type.samples();
+
+        let mut canvas = Canvas::new(info.width as usize, info.height as usize);
+        for (pixel, bytes) in canvas
+            .pixels
+            .iter_mut()
+            .zip(data[..info.buffer_size()].chunks_exact(channels))
+        {
+            let channel = |i: usize| bytes[i] as f64 / 255.0;
+            *pixel = match info.color_type {
+                png::ColorType::Grayscale | png::ColorType::GrayscaleAlpha => {
+                    Color::new(channel(0), channel(0), channel(0))
+                }
+                _ => Color::new(channel(0), channel(1), channel(2)),
+            };
+        }
+
+        Ok(canvas)
+    }
+
+    pub fn save_png(&self, path: PathBuf) -> Result<(), std::io::Error> {
         let path = path.as_path();
         let file = File::create(path)?;
-        let ref mut w = BufWriter::new(file);
+        let w = &mut BufWriter::new(file);
 
         let mut encoder = png::Encoder::new(w, self.width as u32, self.height as u32);
         encoder.set_color(png::ColorType::Rgb);
@@ -33,14 +62,13 @@ impl Canvas {
         let data = self
             .pixels
             .iter()
-            .map(|c| {
+            .flat_map(|c| {
                 vec![
                     (c.r * 255.0).round().clamp(0.0, 255.0) as u8,
                     (c.g * 255.0).round().clamp(0.0, 255.0) as u8,
                     (c.b * 255.0).round().clamp(0.0, 255.0) as u8,
                 ]
             })
-            .flatten()
             .collect::<Vec<u8>>();
         writer.write_image_data(data.as_slice())?;
 
@@ -0,0 +1,118 @@
+use crate::utils::EPSILON;
+use crate::{
+    BoundingBox, Intersection, Intersections, Material, Matrix4, Ray, Shape, Tuple4, point,
+    position, shape_accessors, vector,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cone {
+    pub transform: Matrix4,
+    pub parent_transform: Matrix4,
+    pub material: Material,
+    pub minimum: f64,
+    pub maximum: f64,
+    pub closed: bool,
+}
+
+impl Cone {
+    pub fn new(transform: Matrix4) -> Self {
+        Self {
+            transform,
+            parent_transform: Matrix4::eye(),
+            material: Material::default(),
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+        }
+    }
+
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        if !self.closed || ray.direction.y.abs() < EPSILON {
+            return;
+        }
+
+        for y in [self.minimum, self.maximum] {
+            let t = (y - ray.origin.y) / ray.direction.y;
+            if check_cap(ray, t, y.abs()) {
+                xs.add(Intersection::new(t, self));
+            }
+        }
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Cone::new(Matrix4::eye())
+    }
+}
+
+fn check_cap(ray: &Ray, t: f64, radius: f64) -> bool {
+    let p = position(ray, t);
+    p.x * p.x + p.z * p.z <= radius * radius
+}
+
+impl Shape for Cone {
+    shape_accessors!();
+
+    fn bounds(&self) -> BoundingBox {
+        let limit = self.minimum.abs().max(self.maximum.abs());
+        BoundingBox::new(
+            point(-limit, self.minimum, -limit),
+            point(limit, self.maximum, limit),
+        )
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let mut intersections = Intersections::new();
+
+        let (o, d) = (ray.origin, ray.direction);
+        let a = d.x * d.x - d.y * d.y + d.z * d.z;
+        let b = 2.0 * o.x * d.x - 2.0 * o.y * d.y + 2.0 * o.z * d.z;
+        let c = o.x * o.x - o.y * o.y + o.z * o.z;
+
+        let ts = if a.abs() < EPSILON {
+            if b.abs() < EPSILON {
+                vec![]
+            } else {
+                vec![-c / (2.0 * b)]
+            }
+        } else {
+            let discriminant = b * b - 4.0 * a * c;
+            if discriminant < 0.0 {
+                return intersections;
+            }
+
+            let sqrt_discriminant = discriminant.sqrt();
+            let t0 = (-b - sqrt_discriminant) / (2.0 * a);
+            let t1 = (-b + sqrt_discriminant) / (2.0 * a);
+            vec![t0.min(t1), t0.max(t1)]
+        };
+
+        for t in ts {
+            let y = o.y + t * d.y;
+            if self.minimum < y && y < self.maximum {
+                intersections.add(Intersection::new(t, self));
+            }
+        }
+
+        self.intersect_caps(ray, &mut intersections);
+        intersections
+    }
+
+    fn local_normal_at(&self, local_point: Tuple4, _hit: &Intersection) -> Tuple4 {
+        let dist = local_point.x * local_point.x + local_point.z * local_point.z;
+
+        if dist < local_point.y * local_point.y && local_point.y >= self.maximum - EPSILON {
+            vector(0.0, 1.0, 0.0)
+        } else if dist < local_point.y * local_point.y && local_point.y <= self.minimum + EPSILON {
+            vector(0.0, -1.0, 0.0)
+        } else {
+            let y = if local_point.y > 0.0 {
+                -dist.sqrt()
+            } else {
+                dist.sqrt()
+            };
+            vector(local_point.x, y, local_point.z)
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    BoundingBox, Intersection, Intersections, Material, Matrix4, Ray, Shape, Tuple4, intersect,
+    parent_space_bounds_of, shape_accessors,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsgOperation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+pub fn intersection_allowed(op: CsgOperation, lhit: bool, inl: bool, inr: bool) -> bool {
+    match op {
+        CsgOperation::Union => (lhit && !inr) || (!lhit && !inl),
+        CsgOperation::Intersection => (lhit && inr) || (!lhit && inl),
+        CsgOperation::Difference => (lhit && !inr) || (!lhit && inl),
+    }
+}
+
+#[derive(Debug)]
+pub struct Csg {
+    transform: Matrix4,
+    parent_transform: Matrix4,
+    material: Material,
+    operation: CsgOperation,
+    left: Box<dyn Shape>,
+    right: Box<dyn Shape>,
+}
+
+impl Csg {
+    pub fn new(operation: CsgOperation, left: Box<dyn Shape>, right: Box<dyn Shape>) -> Self {
+        let mut csg = Self {
+            transform: Matrix4::eye(),
+            parent_transform: Matrix4::eye(),
+            material: Material::default(),
+            operation,
+            left,
+            right,
+        };
+        csg.update_children();
+        csg
+    }
+
+    pub fn operation(&self) -> CsgOperation {
+        self.operation
+    }
+
+    pub fn left(&self) -> &dyn Shape {
+        self.left.as_ref()
+    }
+
+    pub fn right(&self) -> &dyn Shape {
+        self.right.as_ref()
+    }
+
+    pub fn filter_intersections<'a>(&self, xs: Intersections<'a>) -> Intersections<'a> {
+        let mut inl = false;
+        let mut inr = false;
+
+        let mut result = Intersections::new();
+        for i in xs.intersections {
+            let lhit = self.left.includes(i.object);
+
+            if intersection_allowed(self.operation, lhit, inl, inr) {
+                result.add(i);
+            }
+
+            if lhit {
+                inl = !inl;
+            } else {
+                inr = !inr;
+            }
+        }
+        result
+    }
+
+    fn update_children(&mut self) {
+        let world_transform = self.parent_transform * self.transform;
+        self.left.set_parent_transform(world_transform);
+        self.right.set_parent_transform(world_transform);
+    }
+}
+
+impl Shape for Csg {
+    shape_accessors!(update_children);
+
+    fn set_material(&mut self, material: Material) {
+        self.left.set_material(material.clone());
+        self.right.set_material(material.clone());
+        self.material = material;
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        self.left.includes(other) || self.right.includes(other)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::default();
+        bounds.add_box(&parent_space_bounds_of(self.left.as_ref()));
+        bounds.add_box(&parent_space_bounds_of(self.right.as_ref()));
+        bounds
+    }
+
+    fn divide(&mut self, threshold: usize) {
+        self.left.divide(threshold);
+        self.right.divide(threshold);
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        if !self.bounds().intersects(ray) {
+            return Intersections::new();
+        }
+
+        let mut xs = intersect(self.left.as_ref(), ray);
+        xs.extend(intersect(self.right.as_ref(), ray));
+        xs.sort();
+        self.filter_intersections(xs)
+    }
+
+    fn local_normal_at(&self, _local_point: Tuple4, _hit: &Intersection) -> Tuple4 {
+        panic!("csg shapes have no surface, normals come from their children")
+    }
+}
@@ -0,0 +1,87 @@
+use crate::utils::EPSILON;
+use crate::{
+    BoundingBox, Intersection, Intersections, Material, Matrix4, Ray, Shape, Tuple4, point,
+    shape_accessors, vector,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cube {
+    pub transform: Matrix4,
+    pub parent_transform: Matrix4,
+    pub material: Material,
+}
+
+impl Cube {
+    pub fn new(transform: Matrix4) -> Self {
+        Self {
+            transform,
+            parent_transform: Matrix4::eye(),
+            material: Material::default(),
+        }
+    }
+}
+
+impl Default for Cube {
+    fn default() -> Self {
+        Cube::new(Matrix4::eye())
+    }
+}
+
+fn check_axis(origin: f64, direction: f64) -> (f64, f64) {
+    let tmin_numerator = -1.0 - origin;
+    let tmax_numerator = 1.0 - origin;
+
+    let (tmin, tmax) = if direction.abs() >= EPSILON {
+        (tmin_numerator / direction, tmax_numerator / direction)
+    } else {
+        (
+            tmin_numerator * f64::INFINITY,
+            tmax_numerator * f64::INFINITY,
+        )
+    };
+
+    if tmin > tmax {
+        (tmax, tmin)
+    } else {
+        (tmin, tmax)
+    }
+}
+
+impl Shape for Cube {
+    shape_accessors!();
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(point(-1.0, -1.0, -1.0), point(1.0, 1.0, 1.0))
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let (xtmin, xtmax) = check_axis(ray.origin.x, ray.direction.x);
+        let (ytmin, ytmax) = check_axis(ray.origin.y, ray.direction.y);
+        let (ztmin, ztmax) = check_axis(ray.origin.z, ray.direction.z);
+
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+
+        let mut intersections = Intersections::new();
+        if tmin <= tmax {
+            intersections.add(Intersection::new(tmin, self));
+            intersections.add(Intersection::new(tmax, self));
+        }
+        intersections
+    }
+
+    fn local_normal_at(&self, local_point: Tuple4, _hit: &Intersection) -> Tuple4 {
+        let abs_x = local_point.x.abs();
+        let abs_y = local_point.y.abs();
+        let abs_z = local_point.z.abs();
+        let maxc = abs_x.max(abs_y).max(abs_z);
+
+        if maxc == abs_x {
+            vector(local_point.x, 0.0, 0.0)
+        } else if maxc == abs_y {
+            vector(0.0, local_point.y, 0.0)
+        } else {
+            vector(0.0, 0.0, local_point.z)
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::utils::EPSILON;
+use crate::{
+    BoundingBox, Intersection, Intersections, Material, Matrix4, Ray, Shape, Tuple4, point,
+    position, shape_accessors, vector,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cylinder {
+    pub transform: Matrix4,
+    pub parent_transform: Matrix4,
+    pub material: Material,
+    pub minimum: f64,
+    pub maximum: f64,
+    pub closed: bool,
+}
+
+impl Cylinder {
+    pub fn new(transform: Matrix4) -> Self {
+        Self {
+            transform,
+            parent_transform: Matrix4::eye(),
+            material: Material::default(),
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+        }
+    }
+
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Intersections<'a>) {
+        if !self.closed || ray.direction.y.abs() < EPSILON {
+            return;
+        }
+
+        for y in [self.minimum, self.maximum] {
+            let t = (y - ray.origin.y) / ray.direction.y;
+            if check_cap(ray, t) {
+                xs.add(Intersection::new(t, self));
+            }
+        }
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Cylinder::new(Matrix4::eye())
+    }
+}
+
+fn check_cap(ray: &Ray, t: f64) -> bool {
+    let p = position(ray, t);
+    p.x * p.x + p.z * p.z <= 1.0
+}
+
+impl Shape for Cylinder {
+    shape_accessors!();
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            point(-1.0, self.minimum, -1.0),
+            point(1.0, self.maximum, 1.0),
+        )
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let mut intersections = Intersections::new();
+
+        let (o, d) = (ray.origin, ray.direction);
+        let a = d.x * d.x + d.z * d.z;
+        if a.abs() >= EPSILON {
+            let b = 2.0 * o.x * d.x + 2.0 * o.z * d.z;
+            let c = o.x * o.x + o.z * o.z - 1.0;
+
+            let discriminant = b * b - 4.0 * a * c;
+            if discriminant < 0.0 {
+                return intersections;
+            }
+
+            let sqrt_discriminant = discriminant.sqrt();
+            let t0 = (-b - sqrt_discriminant) / (2.0 * a);
+            let t1 = (-b + sqrt_discriminant) / (2.0 * a);
+
+            for t in [t0.min(t1), t0.max(t1)] {
+                let y = o.y + t * d.y;
+                if self.minimum < y && y < self.maximum {
+                    intersections.add(Intersection::new(t, self));
+                }
+            }
+        }
+
+        self.intersect_caps(ray, &mut intersections);
+        intersections
+    }
+
+    fn local_normal_at(&self, local_point: Tuple4, _hit: &Intersection) -> Tuple4 {
+        let dist = local_point.x * local_point.x + local_point.z * local_point.z;
+
+        if dist < 1.0 && local_point.y >= self.maximum - EPSILON {
+            vector(0.0, 1.0, 0.0)
+        } else if dist < 1.0 && local_point.y <= self.minimum + EPSILON {
+            vector(0.0, -1.0, 0.0)
+        } else {
+            vector(local_point.x, 0.0, local_point.z)
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    BoundingBox, Intersection, Intersections, Material, Matrix4, Ray, Shape, Tuple4, intersect,
+    parent_space_bounds_of, shape_accessors,
+};
+
+#[derive(Debug)]
+pub struct Group {
+    transform: Matrix4,
+    parent_transform: Matrix4,
+    material: Material,
+    children: Vec<Box<dyn Shape>>,
+    bounds: BoundingBox,
+}
+
+impl Group {
+    pub fn new(transform: Matrix4) -> Self {
+        Self {
+            transform,
+            parent_transform: Matrix4::eye(),
+            material: Material::default(),
+            children: Vec::new(),
+            bounds: BoundingBox::default(),
+        }
+    }
+
+    pub fn children(&self) -> &[Box<dyn Shape>] {
+        &self.children
+    }
+
+    pub fn add_child(&mut self, mut child: Box<dyn Shape>) {
+        child.set_parent_transform(self.parent_transform * self.transform);
+        self.bounds.add_box(&parent_space_bounds_of(child.as_ref()));
+        self.children.push(child);
+    }
+
+    pub fn partition_children(&mut self) -> Option<(Group, Group)> {
+        let (left, right) = sah_split(&self.children)?;
+
+        let mut children = std::mem::take(&mut self.children)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        let mut take = |indices: Vec<usize>| {
+            let mut group = Group::default();
+            for i in indices {
+                group.add_child(children[i].take().unwrap());
+            }
+            group
+        };
+        let left = take(left);
+        let right = take(right);
+
+        self.children = children.into_iter().flatten().collect();
+        Some((left, right))
+    }
+
+    fn update_children(&mut self) {
+        let world_transform = self.parent_transform * self.transform;
+        for child in &mut self.children {
+            child.set_parent_transform(world_transform);
+        }
+    }
+}
+
+impl Default for Group {
+    fn default() -> Self {
+        Group::new(Matrix4::eye())
+    }
+}
+
+const TRAVERSAL_COST: f64 = 1.0;
+
+fn sort_by_centroid(indices: &mut [usize], bounds: &[BoundingBox], axis: usize) {
+    indices.sort_by(|&a, &b| {
+        let ca = bounds[a].centroid()[axis];
+        let cb = bounds[b].centroid()[axis];
+        ca.partial_cmp(&cb).unwrap()
+    });
+}
+
+fn sah_split(children: &[Box<dyn Shape>]) -> Option<(Vec<usize>, Vec<usize>)> {
+    let bounds = children
+        .iter()
+        .map(|child| parent_space_bounds_of(child.as_ref()))
+        .collect::<Vec<_>>();
+    let mut candidates = (0..children.len())
+        .filter(|&i| bounds[i].is_finite())
+        .collect::<Vec<_>>();
+    if candidates.len() < 2 {
+        return None;
+    }
+
+    let mut total = BoundingBox::default();
+    for &i in &candidates {
+        total.add_box(&bounds[i]);
+    }
+    let total_area = total.surface_area();
+    if total_area <= 0.0 {
+        return None;
+    }
+
+    let n = candidates.len();
+    let mut best: Option<(f64, usize, usize)> = None;
+    for axis in 0..3 {
+        sort_by_centroid(&mut candidates, &bounds, axis);
+
+        let mut right_areas = vec![0.0; n];
+        let mut acc = BoundingBox::default();
+        for k in (1..n).rev() {
+            acc.add_box(&bounds[candidates[k]]);
+            right_areas[k] = acc.surface_area();
+        }
+
+        let mut acc = BoundingBox::default();
+        for k in 1..n {
+            acc.add_box(&bounds[candidates[k - 1]]);
+            let cost = TRAVERSAL_COST
+                + (acc.surface_area() * k as f64 + right_areas[k] * (n - k) as f64) / total_area;
+            if best.is_none_or(|(best_cost, _, _)| cost < best_cost) {
+                best = Some((cost, axis, k));
+            }
+        }
+    }
+
+    let (cost, axis, k) = best?;
+    if cost >= n as f64 {
+        return None;
+    }
+
+    sort_by_centroid(&mut candidates, &bounds, axis);
+    let right = candidates.split_off(k);
+    Some((candidates, right))
+}
+
+impl Shape for Group {
+    shape_accessors!(update_children);
+
+    fn set_material(&mut self, material: Material) {
+        for child in &mut self.children {
+            child.set_material(material.clone());
+        }
+        self.material = material;
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        self.children.iter().any(|child| child.includes(other))
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    fn divide(&mut self, threshold: usize) {
+        if self.children.len() > threshold
+            && let Some((left, right)) = self.partition_children()
+        {
+            self.add_child(Box::new(left));
+            self.add_child(Box::new(right));
+        }
+
+        for child in &mut self.children {
+            child.divide(threshold);
+        }
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let mut intersections = Intersections::new();
+        if !self.bounds.intersects(ray) {
+            return intersections;
+        }
+
+        for child in &self.children {
+            intersections.extend(intersect(child.as_ref(), ray));
+        }
+        intersections.sort();
+        intersections
+    }
+
+    fn local_normal_at(&self, _local_point: Tuple4, _hit: &Intersection) -> Tuple4 {
+        panic!("groups have no surface, normals come from their children")
+    }
+}
@@ -1,21 +1,28 @@
-use crate::{Ray, Sphere, point, transform};
+use crate::utils::EPSILON;
+use crate::{Ray, Shape, Tuple4, normal_at, position};
 use std::ops::Index;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Intersection<'a> {
     pub t: f64,
-    pub object: &'a Sphere,
+    pub object: &'a dyn Shape,
+    pub u: f64,
+    pub v: f64,
 }
 
 impl<'a> Intersection<'a> {
-    pub fn new(t: f64, object: &'a Sphere) -> Self {
-        Self { t, object }
+    pub fn new(t: f64, object: &'a dyn Shape) -> Self {
+        Self::with_uv(t, object, 0.0, 0.0)
+    }
+
+    pub fn with_uv(t: f64, object: &'a dyn Shape, u: f64, v: f64) -> Self {
+        Self { t, object, u, v }
     }
 }
 
 impl<'a> PartialEq for Intersection<'a> {
     fn eq(&self, other: &Self) -> bool {
-        self.t == other.t && std::ptr::eq(self.object, other.object)
+        self.t == other.t && std::ptr::addr_eq(self.object, other.object)
     }
 }
 
@@ -37,6 +44,21 @@ impl<'a> Intersections<'a> {
     pub fn add(&mut self, i: Intersection<'a>) {
         self.intersections.push(i);
     }
+
+    pub fn extend(&mut self, other: Intersections<'a>) {
+        self.intersections.extend(other.intersections);
+    }
+
+    pub fn sort(&mut self) {
+        self.intersections
+            .sort_by(|a, b| a.t.partial_cmp(&b.t).unwrap());
+    }
+}
+
+impl<'a> Default for Intersections<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a> Index<usize> for Intersections<'a> {
@@ -47,32 +69,100 @@ impl<'a> Index<usize> for Intersections<'a> {
     }
 }
 
-pub fn intersect<'a>(sphere: &'a Sphere, ray: &Ray) -> Intersections<'a> {
-    let ray_t = transform(&ray, &sphere.transform.inverse().unwrap());
-    let sphere_to_ray = ray_t.origin - point(0.0, 0.0, 0.0);
-
-    let a = ray_t.direction.dot(&ray_t.direction);
-    let b = 2.0 * ray_t.direction.dot(&sphere_to_ray);
-    let c = sphere_to_ray.dot(&sphere_to_ray) - 1.0;
-
-    let discriminant = b * b - 4.0 * a * c;
-    if discriminant < 0.0 {
-        Intersections::new()
-    } else {
-        let sqrt_discriminant = discriminant.sqrt();
-        let t1 = (-b - sqrt_discriminant) / (2.0 * a);
-        let t2 = (-b + sqrt_discriminant) / (2.0 * a);
-
-        let mut intersections = Intersections::new();
-        intersections.add(Intersection::new(t1, sphere));
-        intersections.add(Intersection::new(t2, sphere));
-        intersections
-    }
-}
-
 pub fn hit<'a>(xs: &'a Intersections<'a>) -> Option<&'a Intersection<'a>> {
     xs.intersections
         .iter()
         .filter(|i| i.t > 0.0)
         .min_by(|a, b| a.t.partial_cmp(&b.t).unwrap())
 }
+
+#[derive(Debug, Clone, Copy)]
+pub struct Computations<'a> {
+    pub t: f64,
+    pub object: &'a dyn Shape,
+    pub point: Tuple4,
+    pub over_point: Tuple4,
+    pub under_point: Tuple4,
+    pub eyev: Tuple4,
+    pub normalv: Tuple4,
+    pub reflectv: Tuple4,
+    pub inside: bool,
+    pub n1: f64,
+    pub n2: f64,
+}
+
+pub fn prepare_computations<'a>(
+    hit: &Intersection<'a>,
+    ray: &Ray,
+    xs: &Intersections<'a>,
+) -> Computations<'a> {
+    let point = position(ray, hit.t);
+    let eyev = -ray.direction;
+    let mut normalv = normal_at(hit.object, point, hit);
+
+    let inside = normalv.dot(&eyev) < 0.0;
+    if inside {
+        normalv = -normalv;
+    }
+
+    let (n1, n2) = refractive_indices(hit, xs);
+
+    Computations {
+        t: hit.t,
+        object: hit.object,
+        point,
+        over_point: point + normalv * EPSILON,
+        under_point: point - normalv * EPSILON,
+        eyev,
+        normalv,
+        reflectv: ray.direction.reflect(&normalv),
+        inside,
+        n1,
+        n2,
+    }
+}
+
+fn refractive_indices(hit: &Intersection, xs: &Intersections) -> (f64, f64) {
+    let outermost = |containers: &[&dyn Shape]| {
+        containers
+            .last()
+            .map_or(1.0, |object| object.material().refractive_index)
+    };
+
+    let mut containers: Vec<&dyn Shape> = Vec::new();
+    for i in &xs.intersections {
+        let n1 = outermost(&containers);
+
+        match containers
+            .iter()
+            .position(|object| std::ptr::addr_eq(*object, i.object))
+        {
+            Some(index) => {
+                containers.remove(index);
+            }
+            None => containers.push(i.object),
+        }
+
+        if i == hit {
+            return (n1, outermost(&containers));
+        }
+    }
+
+    (1.0, 1.0)
+}
+
+pub fn schlick(comps: &Computations) -> f64 {
+    let mut cos = comps.eyev.dot(&comps.normalv);
+
+    if comps.n1 > comps.n2 {
+        let n = comps.n1 / comps.n2;
+        let sin2_t = n * n * (1.0 - cos * cos);
+        if sin2_t > 1.0 {
+            return 1.0;
+        }
+        cos = (1.0 - sin2_t).sqrt();
+    }
+
+    let r0 = ((comps.n1 - comps.n2) / (comps.n1 + comps.n2)).powi(2);
+    r0 + (1.0 - r0) * (1.0 - cos).powi(5)
+}
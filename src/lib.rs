@@ -1,16 +1,57 @@
+mod bounds;
+mod bump;
+mod camera;
 mod canvas;
 mod color;
+mod cone;
+mod csg;
+mod cube;
+mod cylinder;
+mod group;
 mod intersection;
+mod light;
+mod material;
 mod matrix;
+mod noise;
+mod obj_file;
+mod pattern;
+mod plane;
+mod random;
 mod ray;
+mod sampler;
+mod shape;
 mod sphere;
+mod texture;
+mod triangle;
 mod tuple;
 mod utils;
+mod world;
 
+pub use bounds::*;
+pub use bump::*;
+pub use camera::*;
 pub use canvas::*;
 pub use color::*;
+pub use cone::*;
+pub use csg::*;
+pub use cube::*;
+pub use cylinder::*;
+pub use group::*;
 pub use intersection::*;
+pub use light::*;
+pub use material::*;
 pub use matrix::*;
+pub use noise::*;
+pub use obj_file::*;
+pub use pattern::*;
+pub use plane::*;
+pub use random::*;
 pub use ray::*;
+pub use sampler::*;
+pub use shape::*;
 pub use sphere::*;
+pub use texture::*;
+pub use triangle::*;
 pub use tuple::*;
+pub use utils::*;
+pub use world::*;
@@ -0,0 +1,457 @@
+use crate::{Color, Material, Rng, Shape, Tuple4, pattern_at_shape, seed_hash, vector};
+use std::f64::consts::PI;
+use std::fmt::Debug;
+
+pub const MIN_LIGHT_DISTANCE: f64 = 0.01;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Attenuation {
+    pub constant: f64,
+    pub linear: f64,
+    pub quadratic: f64,
+}
+
+impl Attenuation {
+    pub fn new(constant: f64, linear: f64, quadratic: f64) -> Self {
+        Self {
+            constant,
+            linear,
+            quadratic,
+        }
+    }
+
+    pub fn none() -> Self {
+        Self::new(1.0, 0.0, 0.0)
+    }
+
+    pub fn inverse_square() -> Self {
+        Self::new(0.0, 0.0, 1.0)
+    }
+
+    pub fn factor(&self, distance: f64) -> f64 {
+        let distance = distance.max(MIN_LIGHT_DISTANCE);
+        let denominator =
+            self.constant + self.linear * distance + self.quadratic * distance * distance;
+        if denominator <= 0.0 {
+            1.0
+        } else {
+            1.0 / denominator
+        }
+    }
+}
+
+impl Default for Attenuation {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+pub fn candela_from_lumens(lumens: f64, solid_angle: f64) -> f64 {
+    lumens / solid_angle
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightSample {
+    pub direction: Tuple4,
+    pub distance: f64,
+    pub intensity: Color,
+}
+
+impl LightSample {
+    pub fn new(direction: Tuple4, distance: f64, intensity: Color) -> Self {
+        Self {
+            direction,
+            distance,
+            intensity,
+        }
+    }
+
+    pub fn towards(point: Tuple4, position: Tuple4, intensity: Color) -> Self {
+        let v = position - point;
+        Self::new(v.normalize(), v.magnitude(), intensity)
+    }
+
+    pub fn attenuated(self, attenuation: &Attenuation) -> Self {
+        Self {
+            intensity: self.intensity * attenuation.factor(self.distance),
+            ..self
+        }
+    }
+}
+
+pub trait Light: Debug {
+    fn ambient_intensity(&self, point: Tuple4) -> Color;
+    fn samples(&self, point: Tuple4) -> Vec<LightSample>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointLight {
+    pub position: Tuple4,
+    pub intensity: Color,
+    pub attenuation: Attenuation,
+}
+
+impl PointLight {
+    pub fn new(position: Tuple4, intensity: Color) -> Self {
+        Self {
+            position,
+            intensity,
+            attenuation: Attenuation::none(),
+        }
+    }
+
+    pub fn from_lumens(position: Tuple4, color: Color, lumens: f64) -> Self {
+        Self {
+            position,
+            intensity: color * candela_from_lumens(lumens, 4.0 * PI),
+            attenuation: Attenuation::inverse_square(),
+        }
+    }
+}
+
+impl Light for PointLight {
+    fn ambient_intensity(&self, point: Tuple4) -> Color {
+        self.intensity * self.attenuation.factor((self.position - point).magnitude())
+    }
+
+    fn samples(&self, point: Tuple4) -> Vec<LightSample> {
+        vec![
+            LightSample::towards(point, self.position, self.intensity)
+                .attenuated(&self.attenuation),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DirectionalLight {
+    pub direction: Tuple4,
+    pub intensity: Color,
+}
+
+impl DirectionalLight {
+    pub fn new(direction: Tuple4, intensity: Color) -> Self {
+        Self {
+            direction: direction.normalize(),
+            intensity,
+        }
+    }
+
+    pub fn from_lux(direction: Tuple4, color: Color, lux: f64) -> Self {
+        Self::new(direction, color * lux)
+    }
+}
+
+impl Light for DirectionalLight {
+    fn ambient_intensity(&self, _point: Tuple4) -> Color {
+        self.intensity
+    }
+
+    fn samples(&self, _point: Tuple4) -> Vec<LightSample> {
+        vec![LightSample::new(
+            -self.direction,
+            f64::INFINITY,
+            self.intensity,
+        )]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpotLight {
+    pub position: Tuple4,
+    pub direction: Tuple4,
+    pub angle: f64,
+    pub falloff: f64,
+    pub intensity: Color,
+    pub attenuation: Attenuation,
+}
+
+impl SpotLight {
+    pub fn new(position: Tuple4, direction: Tuple4, angle: f64, intensity: Color) -> Self {
+        Self {
+            position,
+            direction: direction.normalize(),
+            angle,
+            falloff: 1.0,
+            intensity,
+            attenuation: Attenuation::none(),
+        }
+    }
+
+    pub fn from_lumens(
+        position: Tuple4,
+        direction: Tuple4,
+        angle: f64,
+        color: Color,
+        lumens: f64,
+    ) -> Self {
+        let solid_angle = 2.0 * PI * (1.0 - angle.cos());
+        Self {
+            intensity: color * candela_from_lumens(lumens, solid_angle),
+            attenuation: Attenuation::inverse_square(),
+            ..Self::new(position, direction, angle, color)
+        }
+    }
+
+    pub fn intensity_towards(&self, point: Tuple4) -> Color {
+        let cos_theta = (point - self.position).normalize().dot(&self.direction);
+        if cos_theta < self.angle.cos() {
+            Color::default()
+        } else {
+            self.intensity * cos_theta.powf(self.falloff)
+        }
+    }
+}
+
+impl Light for SpotLight {
+    fn ambient_intensity(&self, point: Tuple4) -> Color {
+        self.intensity_towards(point) * self.attenuation.factor((self.position - point).magnitude())
+    }
+
+    fn samples(&self, point: Tuple4) -> Vec<LightSample> {
+        vec![
+            LightSample::towards(point, self.position, self.intensity_towards(point))
+                .attenuated(&self.attenuation),
+        ]
+    }
+}
+
+fn rng_for(seed: u64, point: Tuple4) -> Rng {
+    Rng::new(seed_hash(
+        seed,
+        &[point.x.to_bits(), point.y.to_bits(), point.z.to_bits()],
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RectangleLight {
+    pub corner: Tuple4,
+    pub uvec: Tuple4,
+    pub usteps: usize,
+    pub vvec: Tuple4,
+    pub vsteps: usize,
+    pub intensity: Color,
+    pub attenuation: Attenuation,
+    pub jitter: bool,
+    pub seed: u64,
+}
+
+impl RectangleLight {
+    pub fn new(
+        corner: Tuple4,
+        full_uvec: Tuple4,
+        usteps: usize,
+        full_vvec: Tuple4,
+        vsteps: usize,
+        intensity: Color,
+    ) -> Self {
+        let usteps = usteps.max(1);
+        let vsteps = vsteps.max(1);
+        Self {
+            corner,
+            uvec: full_uvec * (1.0 / usteps as f64),
+            usteps,
+            vvec: full_vvec * (1.0 / vsteps as f64),
+            vsteps,
+            intensity,
+            attenuation: Attenuation::none(),
+            jitter: true,
+            seed: 0,
+        }
+    }
+
+    pub fn from_lumens(
+        corner: Tuple4,
+        full_uvec: Tuple4,
+        usteps: usize,
+        full_vvec: Tuple4,
+        vsteps: usize,
+        color: Color,
+        lumens: f64,
+    ) -> Self {
+        Self {
+            attenuation: Attenuation::inverse_square(),
+            ..Self::new(
+                corner,
+                full_uvec,
+                usteps,
+                full_vvec,
+                vsteps,
+                color * candela_from_lumens(lumens, 4.0 * PI),
+            )
+        }
+    }
+
+    pub fn point_on_light(&self, u: f64, v: f64) -> Tuple4 {
+        self.corner + self.uvec * u + self.vvec * v
+    }
+
+    pub fn sample_points(&self, point: Tuple4) -> Vec<Tuple4> {
+        let mut rng = rng_for(self.seed, point);
+        let mut offset = || if self.jitter { rng.next_f64() } else { 0.5 };
+
+        let mut samples = Vec::with_capacity(self.usteps * self.vsteps);
+        for v in 0..self.vsteps {
+            for u in 0..self.usteps {
+                let (du, dv) = (offset(), offset());
+                samples.push(self.point_on_light(u as f64 + du, v as f64 + dv));
+            }
+        }
+        samples
+    }
+}
+
+impl Light for RectangleLight {
+    fn ambient_intensity(&self, point: Tuple4) -> Color {
+        let center = self.point_on_light(self.usteps as f64 / 2.0, self.vsteps as f64 / 2.0);
+        self.intensity * self.attenuation.factor((center - point).magnitude())
+    }
+
+    fn samples(&self, point: Tuple4) -> Vec<LightSample> {
+        self.sample_points(point)
+            .into_iter()
+            .map(|position| {
+                LightSample::towards(point, position, self.intensity).attenuated(&self.attenuation)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SphereLight {
+    pub center: Tuple4,
+    pub radius: f64,
+    pub steps: usize,
+    pub intensity: Color,
+    pub attenuation: Attenuation,
+    pub jitter: bool,
+    pub seed: u64,
+}
+
+impl SphereLight {
+    pub fn new(center: Tuple4, radius: f64, steps: usize, intensity: Color) -> Self {
+        Self {
+            center,
+            radius,
+            steps: steps.max(1),
+            intensity,
+            attenuation: Attenuation::none(),
+            jitter: true,
+            seed: 0,
+        }
+    }
+
+    pub fn from_lumens(
+        center: Tuple4,
+        radius: f64,
+        steps: usize,
+        color: Color,
+        lumens: f64,
+    ) -> Self {
+        Self {
+            attenuation: Attenuation::inverse_square(),
+            ..Self::new(
+                center,
+                radius,
+                steps,
+                color * candela_from_lumens(lumens, 4.0 * PI),
+            )
+        }
+    }
+
+    pub fn sample_points(&self, point: Tuple4) -> Vec<Tuple4> {
+        let mut rng = rng_for(self.seed, point);
+        let mut offset = || if self.jitter { rng.next_f64() } else { 0.5 };
+
+        let axis = (point - self.center).normalize();
+        let helper = if axis.x.abs() < 0.9 {
+            vector(1.0, 0.0, 0.0)
+        } else {
+            vector(0.0, 1.0, 0.0)
+        };
+        let s = helper.cross(&axis).normalize() * self.radius;
+        let t = axis.cross(&s);
+
+        let mut samples = Vec::with_capacity(self.steps * self.steps);
+        for i in 0..self.steps {
+            for j in 0..self.steps {
+                let a = (i as f64 + offset()) / self.steps as f64;
+                let b = (j as f64 + offset()) / self.steps as f64;
+                let r = a.sqrt();
+                let theta = 2.0 * PI * b;
+                samples.push(self.center + s * (r * theta.cos()) + t * (r * theta.sin()));
+            }
+        }
+        samples
+    }
+}
+
+impl Light for SphereLight {
+    fn ambient_intensity(&self, point: Tuple4) -> Color {
+        self.intensity * self.attenuation.factor((self.center - point).magnitude())
+    }
+
+    fn samples(&self, point: Tuple4) -> Vec<LightSample> {
+        self.sample_points(point)
+            .into_iter()
+            .map(|position| {
+                LightSample::towards(point, position, self.intensity).attenuated(&self.attenuation)
+            })
+            .collect()
+    }
+}
+
+pub fn lighting(
+    material: &Material,
+    object: &dyn Shape,
+    light: &dyn Light,
+    point: Tuple4,
+    eyev: Tuple4,
+    normalv: Tuple4,
+    light_intensity: f64,
+) -> Color {
+    let samples = light
+        .samples(point)
+        .into_iter()
+        .map(|sample| LightSample {
+            intensity: sample.intensity * light_intensity,
+            ..sample
+        })
+        .collect::<Vec<_>>();
+    lighting_samples(material, object, light, point, eyev, normalv, &samples)
+}
+
+pub fn lighting_samples(
+    material: &Material,
+    object: &dyn Shape,
+    light: &dyn Light,
+    point: Tuple4,
+    eyev: Tuple4,
+    normalv: Tuple4,
+    samples: &[LightSample],
+) -> Color {
+    let color = match &material.pattern {
+        Some(pattern) => pattern_at_shape(pattern.as_ref(), object, point),
+        None => material.color,
+    };
+    let ambient = color * light.ambient_intensity(point) * material.ambient;
+
+    let mut sum = Color::default();
+    for sample in samples {
+        let lightv = sample.direction;
+        let light_dot_normal = lightv.dot(&normalv);
+        if light_dot_normal < 0.0 {
+            continue;
+        }
+
+        sum = sum + color * sample.intensity * material.diffuse * light_dot_normal;
+
+        let reflectv = (-lightv).reflect(&normalv);
+        let reflect_dot_eye = reflectv.dot(&eyev);
+        if reflect_dot_eye > 0.0 {
+            let factor = reflect_dot_eye.powf(material.shininess);
+            sum = sum + sample.intensity * material.specular * factor;
+        }
+    }
+
+    ambient + sum * (1.0 / samples.len() as f64)
+}
@@ -0,0 +1,65 @@
+use crate::{Color, NormalMap, Pattern};
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+pub struct Material {
+    pub color: Color,
+    pub pattern: Option<Rc<dyn Pattern>>,
+    pub ambient: f64,
+    pub diffuse: f64,
+    pub specular: f64,
+    pub shininess: f64,
+    pub reflective: f64,
+    pub transparency: f64,
+    pub refractive_index: f64,
+    pub normal_map: Option<Rc<dyn NormalMap>>,
+}
+
+impl Material {
+    pub fn new(color: Color, ambient: f64, diffuse: f64, specular: f64, shininess: f64) -> Self {
+        Self {
+            color,
+            pattern: None,
+            ambient,
+            diffuse,
+            specular,
+            shininess,
+            reflective: 0.0,
+            transparency: 0.0,
+            refractive_index: 1.0,
+            normal_map: None,
+        }
+    }
+}
+
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        let same_pattern = match (&self.pattern, &other.pattern) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        let same_normal_map = match (&self.normal_map, &other.normal_map) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_pattern
+            && same_normal_map
+            && self.color == other.color
+            && self.ambient == other.ambient
+            && self.diffuse == other.diffuse
+            && self.specular == other.specular
+            && self.shininess == other.shininess
+            && self.reflective == other.reflective
+            && self.transparency == other.transparency
+            && self.refractive_index == other.refractive_index
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material::new(Color::new(1.0, 1.0, 1.0), 0.1, 0.9, 0.9, 200.0)
+    }
+}
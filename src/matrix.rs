@@ -326,3 +326,17 @@ impl Matrix4 {
         shearing(xy, xz, yx, yz, zx, zy) * self
     }
 }
+
+#[rustfmt::skip]
+pub fn view_transform(from: Tuple4, to: Tuple4, up: Tuple4) -> Matrix4 {
+    let forward = (to - from).normalize();
+    let left = forward.cross(&up.normalize());
+    let true_up = left.cross(&forward);
+    let orientation = Matrix4::from_array([
+        [ left.x,     left.y,     left.z,    0.0],
+        [ true_up.x,  true_up.y,  true_up.z, 0.0],
+        [-forward.x, -forward.y, -forward.z, 0.0],
+        [ 0.0,        0.0,        0.0,       1.0],
+    ]);
+    orientation * translation(-from.x, -from.y, -from.z)
+}
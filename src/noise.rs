@@ -0,0 +1,161 @@
+use crate::{Rng, Tuple4, point};
+
+#[rustfmt::skip]
+const PERMUTATION: [u8; 256] = [
+    151, 160, 137, 91, 90, 15, 131, 13, 201, 95, 96, 53, 194, 233, 7, 225,
+    140, 36, 103, 30, 69, 142, 8, 99, 37, 240, 21, 10, 23, 190, 6, 148,
+    247, 120, 234, 75, 0, 26, 197, 62, 94, 252, 219, 203, 117, 35, 11, 32,
+    57, 177, 33, 88, 237, 149, 56, 87, 174, 20, 125, 136, 171, 168, 68, 175,
+    74, 165, 71, 134, 139, 48, 27, 166, 77, 146, 158, 231, 83, 111, 229, 122,
+    60, 211, 133, 230, 220, 105, 92, 41, 55, 46, 245, 40, 244, 102, 143, 54,
+    65, 25, 63, 161, 1, 216, 80, 73, 209, 76, 132, 187, 208, 89, 18, 169,
+    200, 196, 135, 130, 116, 188, 159, 86, 164, 100, 109, 198, 173, 186, 3, 64,
+    52, 217, 226, 250, 124, 123, 5, 202, 38, 147, 118, 126, 255, 82, 85, 212,
+    207, 206, 59, 227, 47, 16, 58, 17, 182, 189, 28, 42, 223, 183, 170, 213,
+    119, 248, 152, 2, 44, 154, 163, 70, 221, 153, 101, 155, 167, 43, 172, 9,
+    129, 22, 39, 253, 19, 98, 108, 110, 79, 113, 224, 232, 178, 185, 112, 104,
+    218, 246, 97, 228, 251, 34, 242, 193, 238, 210, 144, 12, 191, 179, 162, 241,
+    81, 51, 145, 235, 249, 14, 239, 107, 49, 192, 214, 31, 181, 199, 106, 157,
+    184, 84, 204, 176, 115, 121, 50, 45, 127, 4, 150, 254, 138, 236, 205, 93,
+    222, 114, 67, 29, 24, 72, 243, 141, 128, 195, 78, 66, 215, 61, 156, 180,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Perlin {
+    permutation: [u8; 256],
+}
+
+impl Perlin {
+    pub fn new(seed: u64) -> Self {
+        let mut permutation = [0; 256];
+        for (i, p) in permutation.iter_mut().enumerate() {
+            *p = i as u8;
+        }
+
+        let mut rng = Rng::new(seed);
+        for i in (1..permutation.len()).rev() {
+            permutation.swap(i, rng.below(i + 1));
+        }
+
+        Self { permutation }
+    }
+
+    pub fn noise(&self, point: Tuple4) -> f64 {
+        let (xi, yi, zi) = (
+            (point.x.floor() as i64).rem_euclid(256),
+            (point.y.floor() as i64).rem_euclid(256),
+            (point.z.floor() as i64).rem_euclid(256),
+        );
+        let (x, y, z) = (
+            point.x - point.x.floor(),
+            point.y - point.y.floor(),
+            point.z - point.z.floor(),
+        );
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let hash = |i: i64| self.permutation[i.rem_euclid(256) as usize] as usize;
+        let a = hash(xi) as i64 + yi;
+        let aa = hash(a) as i64 + zi;
+        let ab = hash(a + 1) as i64 + zi;
+        let b = hash(xi + 1) as i64 + yi;
+        let ba = hash(b) as i64 + zi;
+        let bb = hash(b + 1) as i64 + zi;
+
+        lerp(
+            w,
+            lerp(
+                v,
+                lerp(u, grad(hash(aa), x, y, z), grad(hash(ba), x - 1.0, y, z)),
+                lerp(
+                    u,
+                    grad(hash(ab), x, y - 1.0, z),
+                    grad(hash(bb), x - 1.0, y - 1.0, z),
+                ),
+            ),
+            lerp(
+                v,
+                lerp(
+                    u,
+                    grad(hash(aa + 1), x, y, z - 1.0),
+                    grad(hash(ba + 1), x - 1.0, y, z - 1.0),
+                ),
+                lerp(
+                    u,
+                    grad(hash(ab + 1), x, y - 1.0, z - 1.0),
+                    grad(hash(bb + 1), x - 1.0, y - 1.0, z - 1.0),
+                ),
+            ),
+        )
+    }
+
+    pub fn fbm(&self, point: Tuple4, octaves: usize, lacunarity: f64, gain: f64) -> f64 {
+        self.octaves(point, octaves, lacunarity, gain, |n| n)
+    }
+
+    pub fn turbulence(&self, point: Tuple4, octaves: usize) -> f64 {
+        self.octaves(point, octaves, 2.0, 0.5, f64::abs)
+    }
+
+    fn octaves(
+        &self,
+        p: Tuple4,
+        octaves: usize,
+        lacunarity: f64,
+        gain: f64,
+        shape: impl Fn(f64) -> f64,
+    ) -> f64 {
+        let mut sum = 0.0;
+        let mut total_amplitude = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+
+        for _ in 0..octaves {
+            let n = self.noise(point(p.x * frequency, p.y * frequency, p.z * frequency));
+            sum += shape(n) * amplitude;
+            total_amplitude += amplitude;
+            amplitude *= gain;
+            frequency *= lacunarity;
+        }
+
+        if total_amplitude == 0.0 {
+            0.0
+        } else {
+            sum / total_amplitude
+        }
+    }
+}
+
+impl Default for Perlin {
+    fn default() -> Self {
+        Self {
+            permutation: PERMUTATION,
+        }
+    }
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+fn grad(hash: usize, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+    let u = if h & 1 == 0 { u } else { -u };
+    let v = if h & 2 == 0 { v } else { -v };
+    u + v
+}
+
+pub fn perlin_noise(point: Tuple4) -> f64 {
+    Perlin::default().noise(point)
+}
@@ -0,0 +1,283 @@
+use crate::{Group, Shape, SmoothTriangle, Triangle, Tuple2, Tuple4, point, vector};
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum ObjError {
+    Io(std::io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjError::Io(e) => write!(f, "{}", e),
+            ObjError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for ObjError {}
+
+impl From<std::io::Error> for ObjError {
+    fn from(e: std::io::Error) -> Self {
+        ObjError::Io(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Face {
+    Flat(Triangle),
+    Smooth(SmoothTriangle),
+}
+
+impl Face {
+    pub fn to_shape(&self) -> Box<dyn Shape> {
+        match self {
+            Face::Flat(t) => Box::new(t.clone()),
+            Face::Smooth(t) => Box::new(t.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjGroup {
+    pub name: String,
+    pub faces: Vec<Face>,
+}
+
+impl ObjGroup {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            faces: Vec::new(),
+        }
+    }
+
+    pub fn to_group(&self) -> Group {
+        let mut group = Group::default();
+        for face in &self.faces {
+            group.add_child(face.to_shape());
+        }
+        group
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjFile {
+    pub vertices: Vec<Tuple4>,
+    pub normals: Vec<Tuple4>,
+    pub texture_coords: Vec<Tuple2>,
+    pub default_group: ObjGroup,
+    pub groups: Vec<ObjGroup>,
+    pub ignored: usize,
+}
+
+impl ObjFile {
+    pub fn group(&self, name: &str) -> Option<&ObjGroup> {
+        self.groups.iter().find(|g| g.name == name)
+    }
+
+    pub fn to_group(&self) -> Group {
+        let mut group = self.default_group.to_group();
+        for g in &self.groups {
+            group.add_child(Box::new(g.to_group()));
+        }
+        group
+    }
+}
+
+struct FaceVertex {
+    vertex: usize,
+    texture: Option<usize>,
+    normal: Option<usize>,
+}
+
+fn parse_error(line: usize, message: String) -> ObjError {
+    ObjError::Parse { line, message }
+}
+
+fn parse_floats<const N: usize>(line: usize, args: &[&str]) -> Result<[f64; N], ObjError> {
+    if args.len() < N {
+        return Err(parse_error(
+            line,
+            format!("expected {} coordinates, found {}", N, args.len()),
+        ));
+    }
+
+    let mut result = [0.0; N];
+    for (value, arg) in result.iter_mut().zip(args) {
+        *value = arg
+            .parse()
+            .ok()
+            .filter(|v: &f64| v.is_finite())
+            .ok_or_else(|| parse_error(line, format!("invalid number '{}'", arg)))?;
+    }
+    Ok(result)
+}
+
+fn resolve_index(line: usize, index: &str, len: usize, kind: &str) -> Result<usize, ObjError> {
+    let i: i64 = index
+        .parse()
+        .map_err(|_| parse_error(line, format!("invalid {} index '{}'", kind, index)))?;
+
+    let resolved = if i > 0 { i - 1 } else { len as i64 + i };
+    if i == 0 || resolved < 0 || resolved >= len as i64 {
+        return Err(parse_error(
+            line,
+            format!("{} index {} out of range", kind, index),
+        ));
+    }
+    Ok(resolved as usize)
+}
+
+fn parse_face_vertex(line: usize, arg: &str, obj: &ObjFile) -> Result<FaceVertex, ObjError> {
+    let mut parts = arg.split('/');
+
+    let vertex = resolve_index(line, parts.next().unwrap(), obj.vertices.len(), "vertex")?;
+
+    let texture = match parts.next().filter(|s| !s.is_empty()) {
+        Some(texture) => Some(resolve_index(
+            line,
+            texture,
+            obj.texture_coords.len(),
+            "texture",
+        )?),
+        None => None,
+    };
+
+    let normal = match parts.next().filter(|s| !s.is_empty()) {
+        Some(normal) => Some(resolve_index(line, normal, obj.normals.len(), "normal")?),
+        None => None,
+    };
+
+    if parts.next().is_some() {
+        return Err(parse_error(line, format!("invalid face vertex '{}'", arg)));
+    }
+
+    Ok(FaceVertex {
+        vertex,
+        texture,
+        normal,
+    })
+}
+
+fn fan_triangulation(vertices: &[FaceVertex], obj: &ObjFile) -> Vec<Face> {
+    let smooth = vertices.iter().all(|v| v.normal.is_some());
+    let textured = vertices.iter().all(|v| v.texture.is_some());
+
+    (1..vertices.len() - 1)
+        .map(|i| {
+            let (a, b, c) = (&vertices[0], &vertices[i], &vertices[i + 1]);
+            let (p1, p2, p3) = (
+                obj.vertices[a.vertex],
+                obj.vertices[b.vertex],
+                obj.vertices[c.vertex],
+            );
+            let uvs = textured.then(|| {
+                [
+                    obj.texture_coords[a.texture.unwrap()],
+                    obj.texture_coords[b.texture.unwrap()],
+                    obj.texture_coords[c.texture.unwrap()],
+                ]
+            });
+
+            if smooth {
+                let mut triangle = SmoothTriangle::new(
+                    p1,
+                    p2,
+                    p3,
+                    obj.normals[a.normal.unwrap()],
+                    obj.normals[b.normal.unwrap()],
+                    obj.normals[c.normal.unwrap()],
+                );
+                triangle.uvs = uvs;
+                Face::Smooth(triangle)
+            } else {
+                let mut triangle = Triangle::new(p1, p2, p3);
+                triangle.uvs = uvs;
+                Face::Flat(triangle)
+            }
+        })
+        .collect()
+}
+
+pub fn parse_obj_file(contents: &str) -> Result<ObjFile, ObjError> {
+    let mut obj = ObjFile {
+        vertices: Vec::new(),
+        normals: Vec::new(),
+        texture_coords: Vec::new(),
+        default_group: ObjGroup::new("default"),
+        groups: Vec::new(),
+        ignored: 0,
+    };
+    let mut current_group: Option<usize> = None;
+
+    for (i, text) in contents.lines().enumerate() {
+        let line = i + 1;
+        let tokens = text.split_whitespace().collect::<Vec<_>>();
+        let Some((&keyword, args)) = tokens.split_first() else {
+            continue;
+        };
+
+        match keyword {
+            "v" => {
+                let [x, y, z] = parse_floats(line, args)?;
+                obj.vertices.push(point(x, y, z));
+            }
+            "vn" => {
+                let [x, y, z] = parse_floats(line, args)?;
+                obj.normals.push(vector(x, y, z));
+            }
+            "vt" => {
+                let [u] = parse_floats(line, args)?;
+                let v = match args.get(1) {
+                    Some(_) => parse_floats::<2>(line, args)?[1],
+                    None => 0.0,
+                };
+                obj.texture_coords.push(Tuple2::from_array([u, v]));
+            }
+            "f" => {
+                if args.len() < 3 {
+                    return Err(parse_error(
+                        line,
+                        format!("face needs at least 3 vertices, found {}", args.len()),
+                    ));
+                }
+
+                let vertices = args
+                    .iter()
+                    .map(|arg| parse_face_vertex(line, arg, &obj))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let faces = fan_triangulation(&vertices, &obj);
+
+                match current_group {
+                    Some(g) => obj.groups[g].faces.extend(faces),
+                    None => obj.default_group.faces.extend(faces),
+                }
+            }
+            "g" => {
+                let name = args.join(" ");
+                current_group = if name.is_empty() {
+                    None
+                } else {
+                    match obj.groups.iter().position(|g| g.name == name) {
+                        Some(g) => Some(g),
+                        None => {
+                            obj.groups.push(ObjGroup::new(&name));
+                            Some(obj.groups.len() - 1)
+                        }
+                    }
+                };
+            }
+            _ => obj.ignored += 1,
+        }
+    }
+
+    Ok(obj)
+}
+
+pub fn load_obj_file(path: PathBuf) -> Result<ObjFile, ObjError> {
+    let contents = std::fs::read_to_string(path)?;
+    parse_obj_file(&contents)
+}
@@ -0,0 +1,197 @@
+use crate::{Color, Matrix4, Perlin, Shape, Tuple4, point, world_to_object};
+use std::fmt::Debug;
+
+pub trait Pattern: Debug {
+    fn transform(&self) -> &Matrix4;
+    fn set_transform(&mut self, transform: Matrix4);
+    fn pattern_at(&self, pattern_point: Tuple4) -> Color;
+}
+
+pub trait PatternInput: Debug {
+    fn color_at(&self, pattern_point: Tuple4) -> Color;
+}
+
+impl PatternInput for Color {
+    fn color_at(&self, _pattern_point: Tuple4) -> Color {
+        *self
+    }
+}
+
+impl<P: Pattern> PatternInput for P {
+    fn color_at(&self, pattern_point: Tuple4) -> Color {
+        self.pattern_at(self.transform().inverse().unwrap() * pattern_point)
+    }
+}
+
+impl Pattern for Box<dyn Pattern> {
+    fn transform(&self) -> &Matrix4 {
+        self.as_ref().transform()
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.as_mut().set_transform(transform);
+    }
+
+    fn pattern_at(&self, pattern_point: Tuple4) -> Color {
+        self.as_ref().pattern_at(pattern_point)
+    }
+}
+
+pub fn pattern_at_shape(pattern: &dyn Pattern, object: &dyn Shape, world_point: Tuple4) -> Color {
+    let object_point = world_to_object(object, world_point);
+    let pattern_point = pattern.transform().inverse().unwrap() * object_point;
+    pattern.pattern_at(pattern_point)
+}
+
+macro_rules! pattern {
+    ($name:ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name<P = Color> {
+            pub a: P,
+            pub b: P,
+            pub transform: Matrix4,
+        }
+
+        impl<P> $name<P> {
+            pub fn new(a: P, b: P) -> Self {
+                Self {
+                    a,
+                    b,
+                    transform: Matrix4::eye(),
+                }
+            }
+        }
+    };
+}
+
+pattern!(StripePattern);
+pattern!(GradientPattern);
+pattern!(RingPattern);
+pattern!(CheckersPattern);
+pattern!(BlendPattern);
+
+impl<P: PatternInput> Pattern for StripePattern<P> {
+    fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+    }
+
+    fn pattern_at(&self, pattern_point: Tuple4) -> Color {
+        if pattern_point.x.floor().rem_euclid(2.0) == 0.0 {
+            self.a.color_at(pattern_point)
+        } else {
+            self.b.color_at(pattern_point)
+        }
+    }
+}
+
+impl<P: PatternInput> Pattern for GradientPattern<P> {
+    fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+    }
+
+    fn pattern_at(&self, pattern_point: Tuple4) -> Color {
+        let a = self.a.color_at(pattern_point);
+        let b = self.b.color_at(pattern_point);
+        let fraction = pattern_point.x - pattern_point.x.floor();
+        a + (b - a) * fraction
+    }
+}
+
+impl<P: PatternInput> Pattern for RingPattern<P> {
+    fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+    }
+
+    fn pattern_at(&self, pattern_point: Tuple4) -> Color {
+        let distance = pattern_point.x.hypot(pattern_point.z);
+        if distance.floor().rem_euclid(2.0) == 0.0 {
+            self.a.color_at(pattern_point)
+        } else {
+            self.b.color_at(pattern_point)
+        }
+    }
+}
+
+impl<P: PatternInput> Pattern for CheckersPattern<P> {
+    fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+    }
+
+    fn pattern_at(&self, pattern_point: Tuple4) -> Color {
+        let sum = pattern_point.x.floor() + pattern_point.y.floor() + pattern_point.z.floor();
+        if sum.rem_euclid(2.0) == 0.0 {
+            self.a.color_at(pattern_point)
+        } else {
+            self.b.color_at(pattern_point)
+        }
+    }
+}
+
+impl<P: PatternInput> Pattern for BlendPattern<P> {
+    fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+    }
+
+    fn pattern_at(&self, pattern_point: Tuple4) -> Color {
+        (self.a.color_at(pattern_point) + self.b.color_at(pattern_point)) * 0.5
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerturbedPattern<P> {
+    pub pattern: P,
+    pub scale: f64,
+    pub noise: Perlin,
+    pub transform: Matrix4,
+}
+
+impl<P> PerturbedPattern<P> {
+    pub fn new(pattern: P, scale: f64) -> Self {
+        Self {
+            pattern,
+            scale,
+            noise: Perlin::default(),
+            transform: Matrix4::eye(),
+        }
+    }
+}
+
+impl<P: PatternInput> Pattern for PerturbedPattern<P> {
+    fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+    }
+
+    fn pattern_at(&self, pattern_point: Tuple4) -> Color {
+        let (x, y, z) = (pattern_point.x, pattern_point.y, pattern_point.z);
+        let jittered = point(
+            x + self.noise.noise(pattern_point) * self.scale,
+            y + self.noise.noise(point(x, y, z + 1.0)) * self.scale,
+            z + self.noise.noise(point(x, y + 1.0, z)) * self.scale,
+        );
+        self.pattern.color_at(jittered)
+    }
+}
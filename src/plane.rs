@@ -0,0 +1,52 @@
+use crate::utils::EPSILON;
+use crate::{
+    BoundingBox, Intersection, Intersections, Material, Matrix4, Ray, Shape, Tuple4, point,
+    shape_accessors, vector,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plane {
+    pub transform: Matrix4,
+    pub parent_transform: Matrix4,
+    pub material: Material,
+}
+
+impl Plane {
+    pub fn new(transform: Matrix4) -> Self {
+        Self {
+            transform,
+            parent_transform: Matrix4::eye(),
+            material: Material::default(),
+        }
+    }
+}
+
+impl Default for Plane {
+    fn default() -> Self {
+        Plane::new(Matrix4::eye())
+    }
+}
+
+impl Shape for Plane {
+    shape_accessors!();
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            point(f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY),
+            point(f64::INFINITY, 0.0, f64::INFINITY),
+        )
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let mut intersections = Intersections::new();
+        if ray.direction.y.abs() >= EPSILON {
+            let t = -ray.origin.y / ray.direction.y;
+            intersections.add(Intersection::new(t, self));
+        }
+        intersections
+    }
+
+    fn local_normal_at(&self, _local_point: Tuple4, _hit: &Intersection) -> Tuple4 {
+        vector(0.0, 1.0, 0.0)
+    }
+}
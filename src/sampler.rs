@@ -0,0 +1,175 @@
+use crate::{Rng, seed_hash};
+use std::fmt::Debug;
+
+pub trait Sampler: Debug {
+    fn samples(&self, px: usize, py: usize) -> Vec<(f64, f64)>;
+}
+
+fn pixel_rng(seed: u64, px: usize, py: usize) -> Rng {
+    Rng::new(seed_hash(seed, &[px as u64, py as u64]))
+}
+
+fn stratify(count: usize, mut offset: impl FnMut() -> (f64, f64)) -> Vec<(f64, f64)> {
+    let cols = (count as f64).sqrt().ceil() as usize;
+    let rows = count.div_ceil(cols);
+
+    let mut samples = Vec::with_capacity(count);
+    for j in 0..rows {
+        let in_row = (count - j * cols).min(cols);
+        for i in 0..in_row {
+            let (dx, dy) = offset();
+            samples.push((
+                (i as f64 + dx) / in_row as f64,
+                (j as f64 + dy) / rows as f64,
+            ));
+        }
+    }
+    samples
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UniformSampler {
+    pub count: usize,
+}
+
+impl UniformSampler {
+    pub fn new(count: usize) -> Self {
+        Self {
+            count: count.max(1),
+        }
+    }
+}
+
+impl Default for UniformSampler {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl Sampler for UniformSampler {
+    fn samples(&self, _px: usize, _py: usize) -> Vec<(f64, f64)> {
+        stratify(self.count, || (0.5, 0.5))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StratifiedSampler {
+    pub count: usize,
+    pub seed: u64,
+}
+
+impl StratifiedSampler {
+    pub fn new(count: usize, seed: u64) -> Self {
+        Self {
+            count: count.max(1),
+            seed,
+        }
+    }
+}
+
+impl Sampler for StratifiedSampler {
+    fn samples(&self, px: usize, py: usize) -> Vec<(f64, f64)> {
+        let mut rng = pixel_rng(self.seed, px, py);
+        stratify(self.count, || (rng.next_f64(), rng.next_f64()))
+    }
+}
+
+pub fn radical_inverse(base: u64, mut index: u64) -> f64 {
+    let inverse_base = 1.0 / base as f64;
+    let mut factor = inverse_base;
+    let mut result = 0.0;
+    while index > 0 {
+        result += (index % base) as f64 * factor;
+        index /= base;
+        factor *= inverse_base;
+    }
+    result
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HaltonSampler {
+    pub count: usize,
+    pub seed: u64,
+}
+
+impl HaltonSampler {
+    pub fn new(count: usize, seed: u64) -> Self {
+        Self {
+            count: count.max(1),
+            seed,
+        }
+    }
+}
+
+impl Sampler for HaltonSampler {
+    fn samples(&self, px: usize, py: usize) -> Vec<(f64, f64)> {
+        let mut rng = pixel_rng(self.seed, px, py);
+        let (shift_x, shift_y) = (rng.next_f64(), rng.next_f64());
+
+        (1..=self.count as u64)
+            .map(|i| {
+                let x = (radical_inverse(2, i) + shift_x).fract();
+                let y = (radical_inverse(3, i) + shift_y).fract();
+                (x, y)
+            })
+            .collect()
+    }
+}
+
+const SOBOL_SCALE: f64 = 1.0 / (1u64 << 32) as f64;
+
+fn sobol_bits(index: u32) -> (u32, u32) {
+    let mut x = 0;
+    let mut y = 0;
+    let mut v = 1u32 << 31;
+    let mut i = index;
+    let mut bit = 0;
+    while i > 0 {
+        if i & 1 == 1 {
+            x ^= 1u32 << (31 - bit);
+            y ^= v;
+        }
+        v ^= v >> 1;
+        i >>= 1;
+        bit += 1;
+    }
+    (x, y)
+}
+
+pub fn sobol_2d(index: u32) -> (f64, f64) {
+    let (x, y) = sobol_bits(index);
+    (x as f64 * SOBOL_SCALE, y as f64 * SOBOL_SCALE)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SobolSampler {
+    pub count: usize,
+    pub seed: u64,
+}
+
+impl SobolSampler {
+    pub fn new(count: usize, seed: u64) -> Self {
+        Self {
+            count: count.max(1),
+            seed,
+        }
+    }
+}
+
+impl Sampler for SobolSampler {
+    fn samples(&self, px: usize, py: usize) -> Vec<(f64, f64)> {
+        let mut rng = pixel_rng(self.seed, px, py);
+        let scramble_x = rng.next_u64() as u32;
+        let scramble_y = rng.next_u64() as u32;
+
+        (0..self.count as u32)
+            .map(|i| {
+                let (x, y) = sobol_bits(i);
+                (
+                    (x ^ scramble_x) as f64 * SOBOL_SCALE,
+                    (y ^ scramble_y) as f64 * SOBOL_SCALE,
+                )
+            })
+            .collect()
+    }
+}
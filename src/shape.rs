@@ -0,0 +1,91 @@
+use crate::{BoundingBox, Intersection, Intersections, Material, Matrix4, Ray, Tuple4, transform};
+use std::fmt::Debug;
+
+macro_rules! shape_accessors {
+    ($($on_transform:ident)?) => {
+        fn transform(&self) -> &$crate::Matrix4 {
+            &self.transform
+        }
+
+        fn set_transform(&mut self, transform: $crate::Matrix4) {
+            self.transform = transform;
+            $(self.$on_transform();)?
+        }
+
+        fn parent_transform(&self) -> &$crate::Matrix4 {
+            &self.parent_transform
+        }
+
+        fn set_parent_transform(&mut self, transform: $crate::Matrix4) {
+            self.parent_transform = transform;
+            $(self.$on_transform();)?
+        }
+
+        fn material(&self) -> &$crate::Material {
+            &self.material
+        }
+
+        fn material_mut(&mut self) -> &mut $crate::Material {
+            &mut self.material
+        }
+    };
+}
+
+pub(crate) use shape_accessors;
+
+pub trait Shape: Debug {
+    fn transform(&self) -> &Matrix4;
+    fn set_transform(&mut self, transform: Matrix4);
+
+    fn parent_transform(&self) -> &Matrix4;
+    fn set_parent_transform(&mut self, transform: Matrix4);
+
+    fn material(&self) -> &Material;
+    fn material_mut(&mut self) -> &mut Material;
+
+    fn set_material(&mut self, material: Material) {
+        *self.material_mut() = material;
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        std::ptr::addr_eq(self, other)
+    }
+
+    fn bounds(&self) -> BoundingBox;
+
+    fn divide(&mut self, _threshold: usize) {}
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_>;
+    fn local_normal_at(&self, local_point: Tuple4, hit: &Intersection) -> Tuple4;
+}
+
+pub fn intersect<'a>(shape: &'a dyn Shape, ray: &Ray) -> Intersections<'a> {
+    let local_ray = transform(ray, &shape.transform().inverse().unwrap());
+    shape.local_intersect(&local_ray)
+}
+
+pub fn parent_space_bounds_of(shape: &dyn Shape) -> BoundingBox {
+    shape.bounds().transform(shape.transform())
+}
+
+pub fn world_to_object(shape: &dyn Shape, world_point: Tuple4) -> Tuple4 {
+    let world_transform = *shape.parent_transform() * *shape.transform();
+    world_transform.inverse().unwrap() * world_point
+}
+
+pub fn normal_to_world(shape: &dyn Shape, local_normal: Tuple4) -> Tuple4 {
+    let world_transform = *shape.parent_transform() * *shape.transform();
+    let mut world_normal = world_transform.inverse().unwrap().transpose() * local_normal;
+    world_normal.w = 0.0;
+    world_normal.normalize()
+}
+
+pub fn normal_at(shape: &dyn Shape, world_point: Tuple4, hit: &Intersection) -> Tuple4 {
+    let local_point = world_to_object(shape, world_point);
+    let mut local_normal = shape.local_normal_at(local_point, hit);
+    if let Some(normal_map) = &shape.material().normal_map {
+        local_normal.w = 0.0;
+        local_normal = normal_map.perturb_normal(local_point, local_normal.normalize());
+    }
+    normal_to_world(shape, local_normal)
+}
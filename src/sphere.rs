@@ -1,13 +1,22 @@
-use crate::Matrix4;
+use crate::{
+    BoundingBox, Intersection, Intersections, Material, Matrix4, Ray, Shape, Tuple4, point,
+    shape_accessors,
+};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Sphere {
     pub transform: Matrix4,
+    pub parent_transform: Matrix4,
+    pub material: Material,
 }
 
 impl Sphere {
     pub fn new(transform: Matrix4) -> Self {
-        Self { transform }
+        Self {
+            transform,
+            parent_transform: Matrix4::eye(),
+            material: Material::default(),
+        }
     }
 }
 
@@ -16,3 +25,37 @@ impl Default for Sphere {
         Sphere::new(Matrix4::eye())
     }
 }
+
+impl Shape for Sphere {
+    shape_accessors!();
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(point(-1.0, -1.0, -1.0), point(1.0, 1.0, 1.0))
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let sphere_to_ray = ray.origin - point(0.0, 0.0, 0.0);
+
+        let a = ray.direction.dot(&ray.direction);
+        let b = 2.0 * ray.direction.dot(&sphere_to_ray);
+        let c = sphere_to_ray.dot(&sphere_to_ray) - 1.0;
+
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            Intersections::new()
+        } else {
+            let sqrt_discriminant = discriminant.sqrt();
+            let t1 = (-b - sqrt_discriminant) / (2.0 * a);
+            let t2 = (-b + sqrt_discriminant) / (2.0 * a);
+
+            let mut intersections = Intersections::new();
+            intersections.add(Intersection::new(t1, self));
+            intersections.add(Intersection::new(t2, self));
+            intersections
+        }
+    }
+
+    fn local_normal_at(&self, local_point: Tuple4, _hit: &Intersection) -> Tuple4 {
+        local_point - point(0.0, 0.0, 0.0)
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{Canvas, Color, Matrix4, Pattern, Tuple4};
+use std::f64::consts::PI;
+use std::fmt::Debug;
+
+pub trait UvPattern: Debug {
+    fn uv_pattern_at(&self, u: f64, v: f64) -> Color;
+}
+
+impl UvPattern for Box<dyn UvPattern> {
+    fn uv_pattern_at(&self, u: f64, v: f64) -> Color {
+        self.as_ref().uv_pattern_at(u, v)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UvCheckers {
+    pub width: f64,
+    pub height: f64,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl UvCheckers {
+    pub fn new(width: f64, height: f64, a: Color, b: Color) -> Self {
+        Self {
+            width,
+            height,
+            a,
+            b,
+        }
+    }
+}
+
+impl UvPattern for UvCheckers {
+    fn uv_pattern_at(&self, u: f64, v: f64) -> Color {
+        let sum = (u * self.width).floor() + (v * self.height).floor();
+        if sum.rem_euclid(2.0) == 0.0 {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageTexture {
+    pub canvas: Canvas,
+}
+
+impl ImageTexture {
+    pub fn new(canvas: Canvas) -> Self {
+        Self { canvas }
+    }
+}
+
+impl UvPattern for ImageTexture {
+    fn uv_pattern_at(&self, u: f64, v: f64) -> Color {
+        if self.canvas.width == 0 || self.canvas.height == 0 {
+            return Color::default();
+        }
+
+        let max_x = self.canvas.width - 1;
+        let max_y = self.canvas.height - 1;
+
+        let x = u.clamp(0.0, 1.0) * max_x as f64;
+        let y = (1.0 - v.clamp(0.0, 1.0)) * max_y as f64;
+
+        let x0 = x.floor() as usize;
+        let y0 = y.floor() as usize;
+        let x1 = (x0 + 1).min(max_x);
+        let y1 = (y0 + 1).min(max_y);
+        let tx = x - x0 as f64;
+        let ty = y - y0 as f64;
+
+        let top = self.canvas[(x0, y0)] * (1.0 - tx) + self.canvas[(x1, y0)] * tx;
+        let bottom = self.canvas[(x0, y1)] * (1.0 - tx) + self.canvas[(x1, y1)] * tx;
+        top * (1.0 - ty) + bottom * ty
+    }
+}
+
+pub fn spherical_map(p: Tuple4) -> (f64, f64) {
+    let theta = p.x.atan2(p.z);
+    let radius = (p.x * p.x + p.y * p.y + p.z * p.z).sqrt();
+    let phi = (p.y / radius).acos();
+
+    let raw_u = theta / (2.0 * PI);
+    (1.0 - (raw_u + 0.5), 1.0 - phi / PI)
+}
+
+pub fn planar_map(p: Tuple4) -> (f64, f64) {
+    (p.x.rem_euclid(1.0), p.z.rem_euclid(1.0))
+}
+
+pub fn cylindrical_map(p: Tuple4) -> (f64, f64) {
+    let theta = p.x.atan2(p.z);
+    let raw_u = theta / (2.0 * PI);
+    (1.0 - (raw_u + 0.5), p.y.rem_euclid(1.0))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UvMapping {
+    Spherical,
+    Planar,
+    Cylindrical,
+}
+
+impl UvMapping {
+    pub fn map(&self, p: Tuple4) -> (f64, f64) {
+        match self {
+            UvMapping::Spherical => spherical_map(p),
+            UvMapping::Planar => planar_map(p),
+            UvMapping::Cylindrical => cylindrical_map(p),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextureMapPattern<P> {
+    pub uv_pattern: P,
+    pub mapping: UvMapping,
+    pub transform: Matrix4,
+}
+
+impl<P> TextureMapPattern<P> {
+    pub fn new(uv_pattern: P, mapping: UvMapping) -> Self {
+        Self {
+            uv_pattern,
+            mapping,
+            transform: Matrix4::eye(),
+        }
+    }
+}
+
+impl<P: UvPattern> Pattern for TextureMapPattern<P> {
+    fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+    }
+
+    fn pattern_at(&self, pattern_point: Tuple4) -> Color {
+        let (u, v) = self.mapping.map(pattern_point);
+        self.uv_pattern.uv_pattern_at(u, v)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CubeFace {
+    Left,
+    Front,
+    Right,
+    Back,
+    Up,
+    Down,
+}
+
+pub fn face_from_point(p: Tuple4) -> CubeFace {
+    let coord = p.x.abs().max(p.y.abs()).max(p.z.abs());
+
+    if coord == p.x {
+        CubeFace::Right
+    } else if coord == -p.x {
+        CubeFace::Left
+    } else if coord == p.y {
+        CubeFace::Up
+    } else if coord == -p.y {
+        CubeFace::Down
+    } else if coord == p.z {
+        CubeFace::Front
+    } else {
+        CubeFace::Back
+    }
+}
+
+pub fn cube_uv(face: CubeFace, p: Tuple4) -> (f64, f64) {
+    let wrap = |value: f64| value.rem_euclid(2.0) / 2.0;
+
+    match face {
+        CubeFace::Front => (wrap(p.x + 1.0), wrap(p.y + 1.0)),
+        CubeFace::Back => (wrap(1.0 - p.x), wrap(p.y + 1.0)),
+        CubeFace::Left => (wrap(p.z + 1.0), wrap(p.y + 1.0)),
+        CubeFace::Right => (wrap(1.0 - p.z), wrap(p.y + 1.0)),
+        CubeFace::Up => (wrap(p.x + 1.0), wrap(1.0 - p.z)),
+        CubeFace::Down => (wrap(p.x + 1.0), wrap(p.z + 1.0)),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubeMapPattern<P> {
+    pub left: P,
+    pub front: P,
+    pub right: P,
+    pub back: P,
+    pub up: P,
+    pub down: P,
+    pub transform: Matrix4,
+}
+
+impl<P> CubeMapPattern<P> {
+    pub fn new(left: P, front: P, right: P, back: P, up: P, down: P) -> Self {
+        Self {
+            left,
+            front,
+            right,
+            back,
+            up,
+            down,
+            transform: Matrix4::eye(),
+        }
+    }
+}
+
+impl<P: UvPattern> Pattern for CubeMapPattern<P> {
+    fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+    }
+
+    fn pattern_at(&self, pattern_point: Tuple4) -> Color {
+        let face = face_from_point(pattern_point);
+        let (u, v) = cube_uv(face, pattern_point);
+
+        let uv_pattern = match face {
+            CubeFace::Left => &self.left,
+            CubeFace::Front => &self.front,
+            CubeFace::Right => &self.right,
+            CubeFace::Back => &self.back,
+            CubeFace::Up => &self.up,
+            CubeFace::Down => &self.down,
+        };
+        uv_pattern.uv_pattern_at(u, v)
+    }
+}
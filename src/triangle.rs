@@ -0,0 +1,145 @@
+use crate::utils::EPSILON;
+use crate::{
+    BoundingBox, Intersection, Intersections, Material, Matrix4, Ray, Shape, Tuple2, Tuple4,
+    shape_accessors,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Triangle {
+    pub transform: Matrix4,
+    pub parent_transform: Matrix4,
+    pub material: Material,
+    pub p1: Tuple4,
+    pub p2: Tuple4,
+    pub p3: Tuple4,
+    pub e1: Tuple4,
+    pub e2: Tuple4,
+    pub normal: Tuple4,
+    pub uvs: Option<[Tuple2; 3]>,
+}
+
+impl Triangle {
+    pub fn new(p1: Tuple4, p2: Tuple4, p3: Tuple4) -> Self {
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+        Self {
+            transform: Matrix4::eye(),
+            parent_transform: Matrix4::eye(),
+            material: Material::default(),
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal: e2.cross(&e1).normalize(),
+            uvs: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmoothTriangle {
+    pub transform: Matrix4,
+    pub parent_transform: Matrix4,
+    pub material: Material,
+    pub p1: Tuple4,
+    pub p2: Tuple4,
+    pub p3: Tuple4,
+    pub n1: Tuple4,
+    pub n2: Tuple4,
+    pub n3: Tuple4,
+    pub e1: Tuple4,
+    pub e2: Tuple4,
+    pub uvs: Option<[Tuple2; 3]>,
+}
+
+impl SmoothTriangle {
+    pub fn new(p1: Tuple4, p2: Tuple4, p3: Tuple4, n1: Tuple4, n2: Tuple4, n3: Tuple4) -> Self {
+        Self {
+            transform: Matrix4::eye(),
+            parent_transform: Matrix4::eye(),
+            material: Material::default(),
+            p1,
+            p2,
+            p3,
+            n1,
+            n2,
+            n3,
+            e1: p2 - p1,
+            e2: p3 - p1,
+            uvs: None,
+        }
+    }
+}
+
+fn intersect_triangle(p1: Tuple4, e1: Tuple4, e2: Tuple4, ray: &Ray) -> Option<(f64, f64, f64)> {
+    let dir_cross_e2 = ray.direction.cross(&e2);
+    let det = e1.dot(&dir_cross_e2);
+    if det.abs() < EPSILON {
+        return None;
+    }
+
+    let f = 1.0 / det;
+    let p1_to_origin = ray.origin - p1;
+    let u = f * p1_to_origin.dot(&dir_cross_e2);
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let origin_cross_e1 = p1_to_origin.cross(&e1);
+    let v = f * ray.direction.dot(&origin_cross_e1);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t = f * e2.dot(&origin_cross_e1);
+    Some((t, u, v))
+}
+
+impl Shape for Triangle {
+    shape_accessors!();
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::default();
+        bounds.add_point(self.p1);
+        bounds.add_point(self.p2);
+        bounds.add_point(self.p3);
+        bounds
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let mut intersections = Intersections::new();
+        if let Some((t, u, v)) = intersect_triangle(self.p1, self.e1, self.e2, ray) {
+            intersections.add(Intersection::with_uv(t, self, u, v));
+        }
+        intersections
+    }
+
+    fn local_normal_at(&self, _local_point: Tuple4, _hit: &Intersection) -> Tuple4 {
+        self.normal
+    }
+}
+
+impl Shape for SmoothTriangle {
+    shape_accessors!();
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::default();
+        bounds.add_point(self.p1);
+        bounds.add_point(self.p2);
+        bounds.add_point(self.p3);
+        bounds
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let mut intersections = Intersections::new();
+        if let Some((t, u, v)) = intersect_triangle(self.p1, self.e1, self.e2, ray) {
+            intersections.add(Intersection::with_uv(t, self, u, v));
+        }
+        intersections
+    }
+
+    fn local_normal_at(&self, _local_point: Tuple4, hit: &Intersection) -> Tuple4 {
+        self.n2 * hit.u + self.n3 * hit.v + self.n1 * (1.0 - hit.u - hit.v)
+    }
+}
@@ -146,6 +146,10 @@ impl Tuple4 {
             0.0,
         ])
     }
+
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * 2.0 * self.dot(normal)
+    }
 }
 
 pub fn point(x: f64, y: f64, z: f64) -> Tuple4 {
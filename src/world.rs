@@ -0,0 +1,153 @@
+use crate::{
+    Color, Computations, Group, Intersections, Light, LightSample, Ray, Shape, Tuple4, hit,
+    intersect, lighting_samples, parent_space_bounds_of, prepare_computations, schlick,
+};
+
+#[derive(Debug, Default)]
+pub struct World {
+    pub objects: Vec<Box<dyn Shape>>,
+    pub lights: Vec<Box<dyn Light>>,
+}
+
+impl World {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn divide(&mut self, threshold: usize) {
+        let (bounded, unbounded): (Vec<_>, Vec<_>) = std::mem::take(&mut self.objects)
+            .into_iter()
+            .partition(|object| parent_space_bounds_of(object.as_ref()).is_finite());
+        self.objects = unbounded;
+
+        if bounded.len() > 1 {
+            let mut root = Group::default();
+            for object in bounded {
+                root.add_child(object);
+            }
+            self.objects.push(Box::new(root));
+        } else {
+            self.objects.extend(bounded);
+        }
+
+        for object in &mut self.objects {
+            object.divide(threshold);
+        }
+    }
+}
+
+pub fn intersect_world<'a>(world: &'a World, ray: &Ray) -> Intersections<'a> {
+    let mut xs = Intersections::new();
+    for object in &world.objects {
+        xs.extend(intersect(object.as_ref(), ray));
+    }
+    xs.sort();
+    xs
+}
+
+pub fn shade_hit(world: &World, comps: &Computations, remaining: usize) -> Color {
+    let surface = world
+        .lights
+        .iter()
+        .map(|light| {
+            lighting_samples(
+                comps.object.material(),
+                comps.object,
+                light.as_ref(),
+                comps.over_point,
+                comps.eyev,
+                comps.normalv,
+                &visible_samples(world, light.as_ref(), comps.over_point),
+            )
+        })
+        .fold(Color::default(), |acc, c| acc + c);
+
+    let reflected = reflected_color(world, comps, remaining);
+    let refracted = refracted_color(world, comps, remaining);
+
+    let material = comps.object.material();
+    if material.reflective > 0.0 && material.transparency > 0.0 {
+        let reflectance = schlick(comps);
+        surface + reflected * reflectance + refracted * (1.0 - reflectance)
+    } else {
+        surface + reflected + refracted
+    }
+}
+
+pub fn reflected_color(world: &World, comps: &Computations, remaining: usize) -> Color {
+    let reflective = comps.object.material().reflective;
+    if remaining == 0 || reflective == 0.0 {
+        return Color::default();
+    }
+
+    let reflect_ray = Ray::new(comps.over_point, comps.reflectv);
+    color_at(world, &reflect_ray, remaining - 1) * reflective
+}
+
+pub fn refracted_color(world: &World, comps: &Computations, remaining: usize) -> Color {
+    let transparency = comps.object.material().transparency;
+    if remaining == 0 || transparency == 0.0 {
+        return Color::default();
+    }
+
+    let n_ratio = comps.n1 / comps.n2;
+    let cos_i = comps.eyev.dot(&comps.normalv);
+    let sin2_t = n_ratio * n_ratio * (1.0 - cos_i * cos_i);
+    if sin2_t > 1.0 {
+        return Color::default();
+    }
+
+    let cos_t = (1.0 - sin2_t).sqrt();
+    let direction = comps.normalv * (n_ratio * cos_i - cos_t) - comps.eyev * n_ratio;
+    let refract_ray = Ray::new(comps.under_point, direction);
+    color_at(world, &refract_ray, remaining - 1) * transparency
+}
+
+pub fn intensity_at(world: &World, light: &dyn Light, point: Tuple4) -> f64 {
+    let samples = light.samples(point);
+    let lit = samples
+        .iter()
+        .filter(|sample| !is_occluded(world, point, sample))
+        .count();
+    lit as f64 / samples.len() as f64
+}
+
+pub fn visible_samples(world: &World, light: &dyn Light, point: Tuple4) -> Vec<LightSample> {
+    light
+        .samples(point)
+        .into_iter()
+        .map(|sample| {
+            if is_occluded(world, point, &sample) {
+                LightSample {
+                    intensity: Color::default(),
+                    ..sample
+                }
+            } else {
+                sample
+            }
+        })
+        .collect()
+}
+
+pub fn is_occluded(world: &World, point: Tuple4, sample: &LightSample) -> bool {
+    let ray = Ray::new(point, sample.direction);
+
+    let xs = intersect_world(world, &ray);
+    hit(&xs).is_some_and(|h| h.t < sample.distance)
+}
+
+pub fn is_shadowed(world: &World, point: Tuple4, light_position: Tuple4) -> bool {
+    is_occluded(
+        world,
+        point,
+        &LightSample::towards(point, light_position, Color::default()),
+    )
+}
+
+pub fn color_at(world: &World, ray: &Ray, remaining: usize) -> Color {
+    let xs = intersect_world(world, ray);
+    match hit(&xs) {
+        Some(i) => shade_hit(world, &prepare_computations(i, ray, &xs), remaining),
+        None => Color::default(),
+    }
+}
@@ -0,0 +1,471 @@
+use std::f64::consts::{PI, SQRT_2};
+use trtc::Shape;
+
+fn bbox(min: (f64, f64, f64), max: (f64, f64, f64)) -> trtc::BoundingBox {
+    trtc::BoundingBox::new(
+        trtc::point(min.0, min.1, min.2),
+        trtc::point(max.0, max.1, max.2),
+    )
+}
+
+#[test]
+fn test_creating_an_empty_bounding_box() {
+    let b = trtc::BoundingBox::default();
+
+    assert!(b.is_empty());
+    assert_eq!(b.min.x, f64::INFINITY);
+    assert_eq!(b.max.x, f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_adding_points_to_an_empty_bounding_box() {
+    let mut b = trtc::BoundingBox::default();
+
+    b.add_point(trtc::point(-5.0, 2.0, 0.0));
+    b.add_point(trtc::point(7.0, 0.0, -3.0));
+
+    assert_eq!(b.min, trtc::point(-5.0, 0.0, -3.0));
+    assert_eq!(b.max, trtc::point(7.0, 2.0, 0.0));
+}
+
+#[test]
+fn test_adding_one_bounding_box_to_another() {
+    let mut b1 = bbox((-5.0, -2.0, 0.0), (7.0, 4.0, 4.0));
+    let b2 = bbox((8.0, -7.0, -2.0), (14.0, 2.0, 8.0));
+
+    b1.add_box(&b2);
+
+    assert_eq!(b1.min, trtc::point(-5.0, -7.0, -2.0));
+    assert_eq!(b1.max, trtc::point(14.0, 4.0, 8.0));
+}
+
+#[test]
+fn test_checking_to_see_if_a_box_contains_a_given_point() {
+    let b = bbox((5.0, -2.0, 0.0), (11.0, 4.0, 7.0));
+    let examples = [
+        (trtc::point(5.0, -2.0, 0.0), true),
+        (trtc::point(11.0, 4.0, 7.0), true),
+        (trtc::point(8.0, 1.0, 3.0), true),
+        (trtc::point(3.0, 0.0, 3.0), false),
+        (trtc::point(8.0, -4.0, 3.0), false),
+        (trtc::point(8.0, 1.0, -1.0), false),
+        (trtc::point(13.0, 1.0, 3.0), false),
+        (trtc::point(8.0, 5.0, 3.0), false),
+        (trtc::point(8.0, 1.0, 8.0), false),
+    ];
+
+    for (p, result) in examples {
+        assert_eq!(b.contains_point(p), result);
+    }
+}
+
+#[test]
+fn test_checking_to_see_if_a_box_contains_a_given_box() {
+    let b = bbox((5.0, -2.0, 0.0), (11.0, 4.0, 7.0));
+    let examples = [
+        (bbox((5.0, -2.0, 0.0), (11.0, 4.0, 7.0)), true),
+        (bbox((6.0, -1.0, 1.0), (10.0, 3.0, 6.0)), true),
+        (bbox((4.0, -3.0, -1.0), (10.0, 3.0, 6.0)), false),
+        (bbox((6.0, -1.0, 1.0), (12.0, 5.0, 8.0)), false),
+    ];
+
+    for (other, result) in examples {
+        assert_eq!(b.contains_box(&other), result);
+    }
+}
+
+#[test]
+fn test_transforming_a_bounding_box() {
+    let b = bbox((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0));
+    let m = trtc::rotation_x(PI / 4.0) * trtc::rotation_y(PI / 4.0);
+
+    let b2 = b.transform(&m);
+
+    assert_eq!(b2.min, trtc::point(-SQRT_2, -1.70711, -1.70711));
+    assert_eq!(b2.max, trtc::point(SQRT_2, 1.70711, 1.70711));
+}
+
+#[test]
+fn test_transforming_an_infinite_bounding_box() {
+    let b = trtc::Plane::default().bounds();
+
+    let b2 = b.transform(&trtc::translation(1.0, 2.0, 3.0));
+
+    assert_eq!(b2.min.x, f64::NEG_INFINITY);
+    assert_eq!(b2.min.y, 2.0);
+    assert_eq!(b2.max.y, 2.0);
+    assert_eq!(b2.max.z, f64::INFINITY);
+}
+
+#[test]
+fn test_the_surface_area_of_a_bounding_box() {
+    let b = bbox((0.0, 0.0, 0.0), (1.0, 2.0, 3.0));
+
+    assert_eq!(b.surface_area(), 22.0);
+    assert_eq!(trtc::BoundingBox::default().surface_area(), 0.0);
+}
+
+#[test]
+fn test_intersecting_a_ray_with_a_bounding_box_at_the_origin() {
+    let b = bbox((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0));
+    let examples = [
+        (
+            trtc::point(5.0, 0.5, 0.0),
+            trtc::vector(-1.0, 0.0, 0.0),
+            true,
+        ),
+        (
+            trtc::point(-5.0, 0.5, 0.0),
+            trtc::vector(1.0, 0.0, 0.0),
+            true,
+        ),
+        (
+            trtc::point(0.5, 5.0, 0.0),
+            trtc::vector(0.0, -1.0, 0.0),
+            true,
+        ),
+        (
+            trtc::point(0.5, -5.0, 0.0),
+            trtc::vector(0.0, 1.0, 0.0),
+            true,
+        ),
+        (
+            trtc::point(0.5, 0.0, 5.0),
+            trtc::vector(0.0, 0.0, -1.0),
+            true,
+        ),
+        (
+            trtc::point(0.5, 0.0, -5.0),
+            trtc::vector(0.0, 0.0, 1.0),
+            true,
+        ),
+        (
+            trtc::point(0.0, 0.5, 0.0),
+            trtc::vector(0.0, 0.0, 1.0),
+            true,
+        ),
+        (
+            trtc::point(-2.0, 0.0, 0.0),
+            trtc::vector(2.0, 4.0, 6.0),
+            false,
+        ),
+        (
+            trtc::point(0.0, -2.0, 0.0),
+            trtc::vector(6.0, 2.0, 4.0),
+            false,
+        ),
+        (
+            trtc::point(0.0, 0.0, -2.0),
+            trtc::vector(4.0, 6.0, 2.0),
+            false,
+        ),
+        (
+            trtc::point(2.0, 0.0, 2.0),
+            trtc::vector(0.0, 0.0, -1.0),
+            false,
+        ),
+        (
+            trtc::point(0.0, 2.0, 2.0),
+            trtc::vector(0.0, -1.0, 0.0),
+            false,
+        ),
+        (
+            trtc::point(2.0, 2.0, 0.0),
+            trtc::vector(-1.0, 0.0, 0.0),
+            false,
+        ),
+    ];
+
+    for (origin, direction, result) in examples {
+        let r = trtc::Ray::new(origin, direction.normalize());
+
+        assert_eq!(b.intersects(&r), result);
+    }
+}
+
+#[test]
+fn test_a_ray_never_intersects_an_empty_bounding_box() {
+    let b = trtc::BoundingBox::default();
+    let r = trtc::Ray::new(
+        trtc::point(0.0, 0.0, 0.0),
+        trtc::vector(1.0, 1.0, 1.0).normalize(),
+    );
+
+    assert!(!b.intersects(&r));
+}
+
+#[test]
+fn test_intersecting_a_ray_with_a_non_cubic_bounding_box() {
+    let b = bbox((5.0, -2.0, 0.0), (11.0, 4.0, 7.0));
+    let examples = [
+        (
+            trtc::point(15.0, 1.0, 2.0),
+            trtc::vector(-1.0, 0.0, 0.0),
+            true,
+        ),
+        (
+            trtc::point(-5.0, -1.0, 4.0),
+            trtc::vector(1.0, 0.0, 0.0),
+            true,
+        ),
+        (
+            trtc::point(7.0, 6.0, 5.0),
+            trtc::vector(0.0, -1.0, 0.0),
+            true,
+        ),
+        (
+            trtc::point(9.0, -5.0, 6.0),
+            trtc::vector(0.0, 1.0, 0.0),
+            true,
+        ),
+        (
+            trtc::point(8.0, 2.0, 12.0),
+            trtc::vector(0.0, 0.0, -1.0),
+            true,
+        ),
+        (
+            trtc::point(6.0, 0.0, -5.0),
+            trtc::vector(0.0, 0.0, 1.0),
+            true,
+        ),
+        (
+            trtc::point(8.0, 1.0, 3.5),
+            trtc::vector(0.0, 0.0, 1.0),
+            true,
+        ),
+        (
+            trtc::point(9.0, -1.0, -8.0),
+            trtc::vector(2.0, 4.0, 6.0),
+            false,
+        ),
+        (
+            trtc::point(8.0, 3.0, -4.0),
+            trtc::vector(6.0, 2.0, 4.0),
+            false,
+        ),
+        (
+            trtc::point(9.0, -1.0, -2.0),
+            trtc::vector(4.0, 6.0, 2.0),
+            false,
+        ),
+        (
+            trtc::point(4.0, 0.0, 9.0),
+            trtc::vector(0.0, 0.0, -1.0),
+            false,
+        ),
+        (
+            trtc::point(8.0, 6.0, -1.0),
+            trtc::vector(0.0, -1.0, 0.0),
+            false,
+        ),
+        (
+            trtc::point(12.0, 5.0, 4.0),
+            trtc::vector(-1.0, 0.0, 0.0),
+            false,
+        ),
+    ];
+
+    for (origin, direction, result) in examples {
+        let r = trtc::Ray::new(origin, direction.normalize());
+
+        assert_eq!(b.intersects(&r), result);
+    }
+}
+
+#[test]
+fn test_a_sphere_has_a_bounding_box() {
+    let s = trtc::Sphere::default();
+
+    assert_eq!(s.bounds(), bbox((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0)));
+}
+
+#[test]
+fn test_a_plane_has_a_bounding_box() {
+    let p = trtc::Plane::default();
+
+    let b = p.bounds();
+
+    assert_eq!(b.min.x, f64::NEG_INFINITY);
+    assert_eq!(b.min.y, 0.0);
+    assert_eq!(b.min.z, f64::NEG_INFINITY);
+    assert_eq!(b.max.x, f64::INFINITY);
+    assert_eq!(b.max.y, 0.0);
+    assert_eq!(b.max.z, f64::INFINITY);
+}
+
+#[test]
+fn test_a_cube_has_a_bounding_box() {
+    let c = trtc::Cube::default();
+
+    assert_eq!(c.bounds(), bbox((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0)));
+}
+
+#[test]
+fn test_a_bounded_cylinder_has_a_bounding_box() {
+    let cyl = trtc::Cylinder {
+        minimum: -5.0,
+        maximum: 3.0,
+        ..Default::default()
+    };
+
+    assert_eq!(cyl.bounds(), bbox((-1.0, -5.0, -1.0), (1.0, 3.0, 1.0)));
+}
+
+#[test]
+fn test_a_bounded_cone_has_a_bounding_box() {
+    let shape = trtc::Cone {
+        minimum: -5.0,
+        maximum: 3.0,
+        ..Default::default()
+    };
+
+    assert_eq!(shape.bounds(), bbox((-5.0, -5.0, -5.0), (5.0, 3.0, 5.0)));
+}
+
+#[test]
+fn test_a_triangle_has_a_bounding_box() {
+    let t = trtc::Triangle::new(
+        trtc::point(-3.0, 7.0, 2.0),
+        trtc::point(6.0, 2.0, -4.0),
+        trtc::point(2.0, -1.0, -1.0),
+    );
+
+    assert_eq!(t.bounds(), bbox((-3.0, -1.0, -4.0), (6.0, 7.0, 2.0)));
+}
+
+#[test]
+fn test_querying_a_shapes_bounding_box_in_its_parents_space() {
+    let s = trtc::Sphere::new(
+        trtc::Matrix4::eye()
+            .scale(0.5, 2.0, 4.0)
+            .translate(1.0, -3.0, 5.0),
+    );
+
+    let b = trtc::parent_space_bounds_of(&s);
+
+    assert_eq!(b, bbox((0.5, -5.0, 1.0), (1.5, -1.0, 9.0)));
+}
+
+#[test]
+fn test_a_group_has_a_bounding_box_that_contains_its_children() {
+    let s = trtc::Sphere::new(
+        trtc::Matrix4::eye()
+            .scale(2.0, 2.0, 2.0)
+            .translate(2.0, 5.0, -3.0),
+    );
+    let c = trtc::Cylinder {
+        minimum: -2.0,
+        maximum: 2.0,
+        ..trtc::Cylinder::new(
+            trtc::Matrix4::eye()
+                .scale(0.5, 1.0, 0.5)
+                .translate(-4.0, -1.0, 4.0),
+        )
+    };
+    let mut g = trtc::Group::default();
+    g.add_child(Box::new(s));
+    g.add_child(Box::new(c));
+
+    assert_eq!(g.bounds(), bbox((-4.5, -3.0, -5.0), (4.0, 7.0, 4.5)));
+}
+
+#[test]
+fn test_a_csg_shape_has_a_bounding_box_that_contains_its_children() {
+    let c = trtc::Csg::new(
+        trtc::CsgOperation::Difference,
+        Box::new(trtc::Sphere::default()),
+        Box::new(trtc::Sphere::new(trtc::translation(2.0, 3.0, 4.0))),
+    );
+
+    assert_eq!(c.bounds(), bbox((-1.0, -1.0, -1.0), (3.0, 4.0, 5.0)));
+}
+
+#[test]
+fn test_partitioning_a_groups_children_separates_distant_clusters() {
+    let mut g = trtc::Group::default();
+    for x in [-10.0, 9.0, -9.0, 10.0] {
+        g.add_child(Box::new(trtc::Sphere::new(trtc::translation(x, 0.0, 0.0))));
+    }
+
+    let (left, right) = g.partition_children().unwrap();
+
+    assert!(g.children().is_empty());
+    assert_eq!(left.children().len(), 2);
+    assert_eq!(right.children().len(), 2);
+    assert_eq!(left.bounds(), bbox((-11.0, -1.0, -1.0), (-8.0, 1.0, 1.0)));
+    assert_eq!(right.bounds(), bbox((8.0, -1.0, -1.0), (11.0, 1.0, 1.0)));
+}
+
+#[test]
+fn test_partitioning_leaves_unbounded_children_in_place() {
+    let mut g = trtc::Group::default();
+    g.add_child(Box::new(trtc::Plane::default()));
+    g.add_child(Box::new(trtc::Sphere::new(trtc::translation(
+        -10.0, 0.0, 0.0,
+    ))));
+    g.add_child(Box::new(trtc::Sphere::new(trtc::translation(
+        10.0, 0.0, 0.0,
+    ))));
+
+    let (left, right) = g.partition_children().unwrap();
+
+    assert_eq!(g.children().len(), 1);
+    assert_eq!(left.children().len(), 1);
+    assert_eq!(right.children().len(), 1);
+}
+
+#[test]
+fn test_partitioning_overlapping_children_is_not_worth_it() {
+    let mut g = trtc::Group::default();
+    g.add_child(Box::new(trtc::Sphere::default()));
+    g.add_child(Box::new(trtc::Sphere::new(trtc::scaling(0.9, 0.9, 0.9))));
+
+    assert!(g.partition_children().is_none());
+    assert_eq!(g.children().len(), 2);
+}
+
+#[test]
+fn test_subdividing_a_group_below_the_threshold_does_nothing() {
+    let mut g = trtc::Group::default();
+    g.add_child(Box::new(trtc::Sphere::new(trtc::translation(
+        -10.0, 0.0, 0.0,
+    ))));
+    g.add_child(Box::new(trtc::Sphere::new(trtc::translation(
+        10.0, 0.0, 0.0,
+    ))));
+
+    g.divide(2);
+
+    assert_eq!(g.children().len(), 2);
+}
+
+#[test]
+fn test_subdividing_a_group_preserves_its_intersections() {
+    let mut g = trtc::Group::new(trtc::rotation_y(PI / 6.0));
+    for i in 0..8 {
+        for j in 0..8 {
+            let x = i as f64 * 3.0 - 10.5;
+            let y = j as f64 * 3.0 - 10.5;
+            g.add_child(Box::new(trtc::Sphere::new(trtc::translation(x, y, 0.0))));
+        }
+    }
+    let r = trtc::Ray::new(trtc::point(1.5, 1.5, -20.0), trtc::vector(0.0, 0.0, 1.0));
+    let before = trtc::intersect(&g, &r)
+        .intersections
+        .iter()
+        .map(|i| i.t)
+        .collect::<Vec<_>>();
+
+    g.divide(4);
+
+    assert!(!before.is_empty());
+    assert_eq!(g.children().len(), 2);
+    let xs = trtc::intersect(&g, &r);
+    assert_eq!(xs.count(), before.len());
+    for (i, t) in before.iter().enumerate() {
+        assert!((xs[i].t - t).abs() < 0.00001);
+    }
+    let n = trtc::normal_at(xs[0].object, trtc::position(&r, xs[0].t), &xs[0]);
+    assert!((n.magnitude() - 1.0).abs() < 0.00001);
+    assert!(n.dot(&r.direction) < 0.0);
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use common::default_world;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+#[test]
+fn test_constructing_a_camera() {
+    let c = trtc::Camera::new(160, 120, PI / 2.0);
+
+    assert_eq!(c.hsize, 160);
+    assert_eq!(c.vsize, 120);
+    assert_eq!(c.field_of_view, PI / 2.0);
+    assert_eq!(c.transform, trtc::Matrix4::eye());
+}
+
+#[test]
+fn test_the_pixel_size_for_a_horizontal_canvas() {
+    let c = trtc::Camera::new(200, 125, PI / 2.0);
+
+    assert!((c.pixel_size() - 0.01).abs() < 0.00001);
+}
+
+#[test]
+fn test_the_pixel_size_for_a_vertical_canvas() {
+    let c = trtc::Camera::new(125, 200, PI / 2.0);
+
+    assert!((c.pixel_size() - 0.01).abs() < 0.00001);
+}
+
+#[test]
+fn test_constructing_a_ray_through_the_center_of_the_canvas() {
+    let c = trtc::Camera::new(201, 101, PI / 2.0);
+
+    let r = c.ray_for_pixel(100, 50);
+
+    assert_eq!(r.origin, trtc::point(0.0, 0.0, 0.0));
+    assert_eq!(r.direction, trtc::vector(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn test_constructing_a_ray_through_a_corner_of_the_canvas() {
+    let c = trtc::Camera::new(201, 101, PI / 2.0);
+
+    let r = c.ray_for_pixel(0, 0);
+
+    assert_eq!(r.origin, trtc::point(0.0, 0.0, 0.0));
+    assert_eq!(r.direction, trtc::vector(0.66519, 0.33259, -0.66851));
+}
+
+#[test]
+fn test_constructing_a_ray_when_the_camera_is_transformed() {
+    let mut c = trtc::Camera::new(201, 101, PI / 2.0);
+    c.transform = trtc::Matrix4::eye()
+        .translate(0.0, -2.0, 5.0)
+        .rotate_y(PI / 4.0);
+
+    let r = c.ray_for_pixel(100, 50);
+
+    assert_eq!(r.origin, trtc::point(0.0, 2.0, -5.0));
+    assert_eq!(
+        r.direction,
+        trtc::vector(FRAC_1_SQRT_2, 0.0, -FRAC_1_SQRT_2)
+    );
+}
+
+#[test]
+fn test_rendering_a_world_with_a_camera() {
+    let w = default_world();
+    let mut c = trtc::Camera::new(11, 11, PI / 2.0);
+    let from = trtc::point(0.0, 0.0, -5.0);
+    let to = trtc::point(0.0, 0.0, 0.0);
+    let up = trtc::vector(0.0, 1.0, 0.0);
+    c.transform = trtc::view_transform(from, to, up);
+
+    let image = trtc::render(&c, &w);
+
+    assert_eq!(image[(5, 5)], trtc::Color::new(0.38066, 0.47583, 0.2855));
+}
+
+#[test]
+fn test_a_camera_limits_recursion_depth_by_default() {
+    let c = trtc::Camera::new(160, 120, PI / 2.0);
+
+    assert_eq!(c.max_depth, 5);
+}
+
+#[test]
+fn test_constructing_a_ray_through_an_offset_within_a_pixel() {
+    let c = trtc::Camera::new(201, 101, PI / 2.0);
+
+    let centered = c.ray_for_pixel_offset(100, 50, 0.5, 0.5);
+    let corner = c.ray_for_pixel_offset(100, 50, 0.0, 0.0);
+
+    assert_eq!(centered, c.ray_for_pixel(100, 50));
+    assert_eq!(
+        corner.direction,
+        trtc::vector(1.0 / 201.0, 1.0 / 201.0, -1.0).normalize()
+    );
+}
+
+#[test]
+fn test_exposure_brings_lumen_lit_scenes_into_range() {
+    let w = trtc::World {
+        objects: vec![Box::new(trtc::Sphere::default())],
+        lights: vec![Box::new(trtc::PointLight::from_lumens(
+            trtc::point(0.0, 0.0, -3.0),
+            trtc::Color::new(1.0, 1.0, 1.0),
+            800.0,
+        ))],
+    };
+    let mut c = trtc::Camera::new(1, 1, PI / 4.0);
+    c.transform = trtc::view_transform(
+        trtc::point(0.0, 0.0, -5.0),
+        trtc::point(0.0, 0.0, 0.0),
+        trtc::vector(0.0, 1.0, 0.0),
+    );
+
+    let unexposed = trtc::render(&c, &w)[(0, 0)];
+    c.exposure = 1.0 / 32.0;
+    let exposed = trtc::render(&c, &w)[(0, 0)];
+
+    assert_eq!(trtc::Camera::new(1, 1, PI / 4.0).exposure, 1.0);
+    assert!(unexposed.r > 1.0);
+    assert_eq!(exposed, unexposed * (1.0 / 32.0));
+    for channel in [exposed.r, exposed.g, exposed.b] {
+        assert!((0.0..=1.0).contains(&channel));
+    }
+}
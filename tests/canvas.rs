@@ -1,5 +1,3 @@
-use trtc;
-
 #[test]
 fn test_creating_a_canvas() {
     let c = trtc::Canvas::new(10, 20);
@@ -23,3 +21,30 @@ fn test_writing_pixels_to_a_canvas() {
 
     assert_eq!(c[(2, 3)], red);
 }
+
+#[test]
+fn test_loading_a_png_round_trips_a_saved_canvas() {
+    let mut c = trtc::Canvas::new(3, 2);
+    c[(0, 0)] = trtc::Color::new(1.0, 0.0, 0.0);
+    c[(1, 0)] = trtc::Color::new(0.0, 1.0, 0.0);
+    c[(2, 0)] = trtc::Color::new(0.0, 0.0, 1.0);
+    c[(0, 1)] = trtc::Color::new(51.0 / 255.0, 102.0 / 255.0, 153.0 / 255.0);
+    c[(2, 1)] = trtc::Color::new(1.0, 1.0, 1.0);
+    let path = std::env::temp_dir().join(format!("trtc-round-trip-{}.png", std::process::id()));
+
+    c.save_png(path.clone()).unwrap();
+    let loaded = trtc::Canvas::load_png(path.clone());
+    std::fs::remove_file(path).unwrap();
+    let loaded = loaded.unwrap();
+
+    assert_eq!(loaded.width, 3);
+    assert_eq!(loaded.height, 2);
+    assert_eq!(loaded.pixels, c.pixels);
+}
+
+#[test]
+fn test_loading_a_missing_png_fails() {
+    let path = std::env::temp_dir().join("trtc-this-file-does-not-exist.png");
+
+    assert!(trtc::Canvas::load_png(path).is_err());
+}
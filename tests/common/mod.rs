@@ -0,0 +1,62 @@
+#![allow(dead_code)]
+
+pub fn white() -> trtc::Color {
+    trtc::Color::new(1.0, 1.0, 1.0)
+}
+
+pub fn black() -> trtc::Color {
+    trtc::Color::new(0.0, 0.0, 0.0)
+}
+
+pub fn default_world() -> trtc::World {
+    let mut s1 = trtc::Sphere::default();
+    s1.material.color = trtc::Color::new(0.8, 1.0, 0.6);
+    s1.material.diffuse = 0.7;
+    s1.material.specular = 0.2;
+
+    let s2 = trtc::Sphere::new(trtc::scaling(0.5, 0.5, 0.5));
+
+    trtc::World {
+        objects: vec![Box::new(s1), Box::new(s2)],
+        lights: vec![Box::new(trtc::PointLight::new(
+            trtc::point(-10.0, 10.0, -10.0),
+            trtc::Color::new(1.0, 1.0, 1.0),
+        ))],
+    }
+}
+
+#[derive(Debug)]
+pub struct TestPattern {
+    transform: trtc::Matrix4,
+}
+
+impl TestPattern {
+    pub fn new() -> Self {
+        Self {
+            transform: trtc::Matrix4::eye(),
+        }
+    }
+}
+
+impl trtc::Pattern for TestPattern {
+    fn transform(&self) -> &trtc::Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: trtc::Matrix4) {
+        self.transform = transform;
+    }
+
+    fn pattern_at(&self, pattern_point: trtc::Tuple4) -> trtc::Color {
+        trtc::Color::new(pattern_point.x, pattern_point.y, pattern_point.z)
+    }
+}
+
+#[derive(Debug)]
+pub struct SolidUv(pub trtc::Color);
+
+impl trtc::UvPattern for SolidUv {
+    fn uv_pattern_at(&self, _u: f64, _v: f64) -> trtc::Color {
+        self.0
+    }
+}
@@ -0,0 +1,111 @@
+use trtc::Shape;
+
+#[test]
+fn test_intersecting_a_cone_with_a_ray() {
+    let shape = trtc::Cone::default();
+    let examples = [
+        (
+            trtc::point(0.0, 0.0, -5.0),
+            trtc::vector(0.0, 0.0, 1.0),
+            5.0,
+            5.0,
+        ),
+        (
+            trtc::point(0.0, 0.0, -5.0),
+            trtc::vector(1.0, 1.0, 1.0),
+            8.66025,
+            8.66025,
+        ),
+        (
+            trtc::point(1.0, 1.0, -5.0),
+            trtc::vector(-0.5, -1.0, 1.0),
+            4.55006,
+            49.44994,
+        ),
+    ];
+
+    for (origin, direction, t0, t1) in examples {
+        let r = trtc::Ray::new(origin, direction.normalize());
+
+        let xs = shape.local_intersect(&r);
+
+        assert_eq!(xs.count(), 2);
+        assert!((xs[0].t - t0).abs() < 0.0001);
+        assert!((xs[1].t - t1).abs() < 0.0001);
+    }
+}
+
+#[test]
+fn test_intersecting_a_cone_with_a_ray_parallel_to_one_of_its_halves() {
+    let shape = trtc::Cone::default();
+    let direction = trtc::vector(0.0, 1.0, 1.0).normalize();
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -1.0), direction);
+
+    let xs = shape.local_intersect(&r);
+
+    assert_eq!(xs.count(), 1);
+    assert!((xs[0].t - 0.35355).abs() < 0.00001);
+}
+
+#[test]
+fn test_intersecting_a_cones_end_caps() {
+    let shape = trtc::Cone {
+        minimum: -0.5,
+        maximum: 0.5,
+        closed: true,
+        ..Default::default()
+    };
+    let examples = [
+        (trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 1.0, 0.0), 0),
+        (trtc::point(0.0, 0.0, -0.25), trtc::vector(0.0, 1.0, 1.0), 2),
+        (trtc::point(0.0, 0.0, -0.25), trtc::vector(0.0, 1.0, 0.0), 4),
+    ];
+
+    for (origin, direction, count) in examples {
+        let r = trtc::Ray::new(origin, direction.normalize());
+
+        let xs = shape.local_intersect(&r);
+
+        assert_eq!(xs.count(), count);
+    }
+}
+
+#[test]
+fn test_computing_the_normal_vector_on_a_cone() {
+    let shape = trtc::Cone::default();
+    let examples = [
+        (trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 0.0, 0.0)),
+        (
+            trtc::point(1.0, 1.0, 1.0),
+            trtc::vector(1.0, -(2.0_f64.sqrt()), 1.0),
+        ),
+        (trtc::point(-1.0, -1.0, 0.0), trtc::vector(-1.0, 1.0, 0.0)),
+    ];
+
+    let i = trtc::Intersection::new(0.0, &shape);
+
+    for (point, normal) in examples {
+        assert_eq!(shape.local_normal_at(point, &i), normal);
+    }
+}
+
+#[test]
+fn test_the_normal_vector_on_a_cones_end_caps() {
+    let shape = trtc::Cone {
+        minimum: -1.0,
+        maximum: 1.0,
+        closed: true,
+        ..Default::default()
+    };
+
+    let i = trtc::Intersection::new(0.0, &shape);
+
+    assert_eq!(
+        shape.local_normal_at(trtc::point(0.5, 1.0, 0.0), &i),
+        trtc::vector(0.0, 1.0, 0.0)
+    );
+    assert_eq!(
+        shape.local_normal_at(trtc::point(0.5, -1.0, 0.0), &i),
+        trtc::vector(0.0, -1.0, 0.0)
+    );
+}
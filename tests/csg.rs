@@ -0,0 +1,172 @@
+use trtc::{CsgOperation, Shape};
+
+#[test]
+fn test_csg_is_created_with_an_operation_and_two_shapes() {
+    let s1 = trtc::Sphere::default();
+    let s2 = trtc::Cube::default();
+
+    let c = trtc::Csg::new(
+        CsgOperation::Union,
+        Box::new(s1.clone()),
+        Box::new(s2.clone()),
+    );
+
+    assert_eq!(c.operation(), CsgOperation::Union);
+    assert_eq!(*c.left().transform(), s1.transform);
+    assert_eq!(*c.right().transform(), s2.transform);
+}
+
+#[test]
+fn test_evaluating_the_rule_for_a_csg_operation() {
+    use CsgOperation::*;
+    #[rustfmt::skip]
+    let examples = [
+        (Union,        true,  true,  true,  false),
+        (Union,        true,  true,  false, true ),
+        (Union,        true,  false, true,  false),
+        (Union,        true,  false, false, true ),
+        (Union,        false, true,  true,  false),
+        (Union,        false, true,  false, false),
+        (Union,        false, false, true,  true ),
+        (Union,        false, false, false, true ),
+        (Intersection, true,  true,  true,  true ),
+        (Intersection, true,  true,  false, false),
+        (Intersection, true,  false, true,  true ),
+        (Intersection, true,  false, false, false),
+        (Intersection, false, true,  true,  true ),
+        (Intersection, false, true,  false, true ),
+        (Intersection, false, false, true,  false),
+        (Intersection, false, false, false, false),
+        (Difference,   true,  true,  true,  false),
+        (Difference,   true,  true,  false, true ),
+        (Difference,   true,  false, true,  false),
+        (Difference,   true,  false, false, true ),
+        (Difference,   false, true,  true,  true ),
+        (Difference,   false, true,  false, true ),
+        (Difference,   false, false, true,  false),
+        (Difference,   false, false, false, false),
+    ];
+
+    for (op, lhit, inl, inr, result) in examples {
+        assert_eq!(trtc::intersection_allowed(op, lhit, inl, inr), result);
+    }
+}
+
+#[test]
+fn test_filtering_a_list_of_intersections() {
+    let examples = [
+        (CsgOperation::Union, 0, 3),
+        (CsgOperation::Intersection, 1, 2),
+        (CsgOperation::Difference, 0, 1),
+    ];
+
+    for (op, x0, x1) in examples {
+        let c = trtc::Csg::new(
+            op,
+            Box::new(trtc::Sphere::default()),
+            Box::new(trtc::Cube::default()),
+        );
+        let s1 = c.left();
+        let s2 = c.right();
+        let mut xs = trtc::Intersections::new();
+        xs.add(trtc::Intersection::new(1.0, s1));
+        xs.add(trtc::Intersection::new(2.0, s2));
+        xs.add(trtc::Intersection::new(3.0, s1));
+        xs.add(trtc::Intersection::new(4.0, s2));
+        let expected = [xs[x0], xs[x1]];
+
+        let result = c.filter_intersections(xs);
+
+        assert_eq!(result.count(), 2);
+        assert_eq!(result[0], expected[0]);
+        assert_eq!(result[1], expected[1]);
+    }
+}
+
+#[test]
+fn test_a_ray_misses_a_csg_object() {
+    let c = trtc::Csg::new(
+        CsgOperation::Union,
+        Box::new(trtc::Sphere::default()),
+        Box::new(trtc::Cube::default()),
+    );
+    let r = trtc::Ray::new(trtc::point(0.0, 2.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = c.local_intersect(&r);
+
+    assert_eq!(xs.count(), 0);
+}
+
+#[test]
+fn test_a_ray_hits_a_csg_object() {
+    let c = trtc::Csg::new(
+        CsgOperation::Union,
+        Box::new(trtc::Sphere::default()),
+        Box::new(trtc::Sphere::new(trtc::translation(0.0, 0.0, 0.5))),
+    );
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = c.local_intersect(&r);
+
+    assert_eq!(xs.count(), 2);
+    assert_eq!(xs[0].t, 4.0);
+    assert!(std::ptr::addr_eq(xs[0].object, c.left()));
+    assert_eq!(xs[1].t, 6.5);
+    assert!(std::ptr::addr_eq(xs[1].object, c.right()));
+}
+
+#[test]
+fn test_a_csg_difference_drills_through_a_cube() {
+    let c = trtc::Csg::new(
+        CsgOperation::Difference,
+        Box::new(trtc::Cube::default()),
+        Box::new(trtc::Cylinder {
+            minimum: -2.0,
+            maximum: 2.0,
+            closed: true,
+            ..trtc::Cylinder::new(trtc::scaling(0.5, 1.0, 0.5))
+        }),
+    );
+    let r = trtc::Ray::new(trtc::point(0.0, 5.0, 0.0), trtc::vector(0.0, -1.0, 0.0));
+
+    let xs = trtc::intersect(&c, &r);
+
+    assert_eq!(xs.count(), 0);
+}
+
+#[test]
+fn test_a_csg_object_includes_its_nested_children() {
+    let mut g = trtc::Group::default();
+    g.add_child(Box::new(trtc::Sphere::default()));
+    let c = trtc::Csg::new(
+        CsgOperation::Union,
+        Box::new(g),
+        Box::new(trtc::Cube::new(trtc::scaling(0.5, 0.5, 0.5))),
+    );
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = c.local_intersect(&r);
+
+    assert_eq!(xs.count(), 2);
+    assert!(c.includes(xs[0].object));
+    assert!(c.left().includes(xs[0].object));
+    assert!(c.left().includes(xs[1].object));
+}
+
+#[test]
+fn test_setting_a_csg_material_updates_both_operands() {
+    let mut c = trtc::Csg::new(
+        CsgOperation::Union,
+        Box::new(trtc::Sphere::default()),
+        Box::new(trtc::Cube::default()),
+    );
+    let m = trtc::Material {
+        color: trtc::Color::new(0.0, 0.0, 1.0),
+        ..Default::default()
+    };
+
+    c.set_material(m.clone());
+
+    assert_eq!(*c.left().material(), m);
+    assert_eq!(*c.right().material(), m);
+}
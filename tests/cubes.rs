@@ -0,0 +1,141 @@
+use trtc::Shape;
+
+#[test]
+fn test_a_ray_intersects_a_cube() {
+    let c = trtc::Cube::default();
+    let examples = [
+        (
+            trtc::point(5.0, 0.5, 0.0),
+            trtc::vector(-1.0, 0.0, 0.0),
+            4.0,
+            6.0,
+        ),
+        (
+            trtc::point(-5.0, 0.5, 0.0),
+            trtc::vector(1.0, 0.0, 0.0),
+            4.0,
+            6.0,
+        ),
+        (
+            trtc::point(0.5, 5.0, 0.0),
+            trtc::vector(0.0, -1.0, 0.0),
+            4.0,
+            6.0,
+        ),
+        (
+            trtc::point(0.5, -5.0, 0.0),
+            trtc::vector(0.0, 1.0, 0.0),
+            4.0,
+            6.0,
+        ),
+        (
+            trtc::point(0.5, 0.0, 5.0),
+            trtc::vector(0.0, 0.0, -1.0),
+            4.0,
+            6.0,
+        ),
+        (
+            trtc::point(0.5, 0.0, -5.0),
+            trtc::vector(0.0, 0.0, 1.0),
+            4.0,
+            6.0,
+        ),
+        (
+            trtc::point(0.0, 0.5, 0.0),
+            trtc::vector(0.0, 0.0, 1.0),
+            -1.0,
+            1.0,
+        ),
+    ];
+
+    for (origin, direction, t1, t2) in examples {
+        let r = trtc::Ray::new(origin, direction);
+
+        let xs = c.local_intersect(&r);
+
+        assert_eq!(xs.count(), 2);
+        assert_eq!(xs[0].t, t1);
+        assert_eq!(xs[1].t, t2);
+    }
+}
+
+#[test]
+fn test_a_ray_misses_a_cube() {
+    let c = trtc::Cube::default();
+    let examples = [
+        (
+            trtc::point(-2.0, 0.0, 0.0),
+            trtc::vector(0.2673, 0.5345, 0.8018),
+        ),
+        (
+            trtc::point(0.0, -2.0, 0.0),
+            trtc::vector(0.8018, 0.2673, 0.5345),
+        ),
+        (
+            trtc::point(0.0, 0.0, -2.0),
+            trtc::vector(0.5345, 0.8018, 0.2673),
+        ),
+        (trtc::point(2.0, 0.0, 2.0), trtc::vector(0.0, 0.0, -1.0)),
+        (trtc::point(0.0, 2.0, 2.0), trtc::vector(0.0, -1.0, 0.0)),
+        (trtc::point(2.0, 2.0, 0.0), trtc::vector(-1.0, 0.0, 0.0)),
+    ];
+
+    for (origin, direction) in examples {
+        let r = trtc::Ray::new(origin, direction);
+
+        let xs = c.local_intersect(&r);
+
+        assert_eq!(xs.count(), 0);
+    }
+}
+
+#[test]
+fn test_the_normal_on_the_surface_of_a_cube() {
+    let c = trtc::Cube::default();
+    let examples = [
+        (trtc::point(1.0, 0.5, -0.8), trtc::vector(1.0, 0.0, 0.0)),
+        (trtc::point(-1.0, -0.2, 0.9), trtc::vector(-1.0, 0.0, 0.0)),
+        (trtc::point(-0.4, 1.0, -0.1), trtc::vector(0.0, 1.0, 0.0)),
+        (trtc::point(0.3, -1.0, -0.7), trtc::vector(0.0, -1.0, 0.0)),
+        (trtc::point(-0.6, 0.3, 1.0), trtc::vector(0.0, 0.0, 1.0)),
+        (trtc::point(0.4, 0.4, -1.0), trtc::vector(0.0, 0.0, -1.0)),
+        (trtc::point(1.0, 1.0, 1.0), trtc::vector(1.0, 0.0, 0.0)),
+        (trtc::point(-1.0, -1.0, -1.0), trtc::vector(-1.0, 0.0, 0.0)),
+    ];
+
+    let i = trtc::Intersection::new(0.0, &c);
+
+    for (point, expected) in examples {
+        let normal = c.local_normal_at(point, &i);
+
+        assert_eq!(normal, expected);
+    }
+}
+
+#[test]
+fn test_intersecting_a_transformed_cube() {
+    let c = trtc::Cube::new(
+        trtc::Matrix4::eye()
+            .scale(1.0, 1.0, 2.0)
+            .rotate_y(std::f64::consts::PI / 2.0),
+    );
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = trtc::intersect(&c, &r);
+
+    assert_eq!(xs.count(), 2);
+    assert_eq!(xs[0].t, 4.0);
+    assert_eq!(xs[1].t, 6.0);
+}
+
+#[test]
+fn test_intersecting_a_cube_rotated_onto_its_edge() {
+    let c = trtc::Cube::new(trtc::rotation_y(std::f64::consts::PI / 4.0));
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = trtc::intersect(&c, &r);
+
+    assert_eq!(xs.count(), 2);
+    assert!((xs[0].t - (5.0 - 2.0_f64.sqrt())).abs() < 0.00001);
+    assert!((xs[1].t - (5.0 + 2.0_f64.sqrt())).abs() < 0.00001);
+}
@@ -0,0 +1,160 @@
+use trtc::Shape;
+
+#[test]
+fn test_a_ray_misses_a_cylinder() {
+    let cyl = trtc::Cylinder::default();
+    let examples = [
+        (trtc::point(1.0, 0.0, 0.0), trtc::vector(0.0, 1.0, 0.0)),
+        (trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 1.0, 0.0)),
+        (trtc::point(0.0, 0.0, -5.0), trtc::vector(1.0, 1.0, 1.0)),
+    ];
+
+    for (origin, direction) in examples {
+        let r = trtc::Ray::new(origin, direction.normalize());
+
+        let xs = cyl.local_intersect(&r);
+
+        assert_eq!(xs.count(), 0);
+    }
+}
+
+#[test]
+fn test_a_ray_strikes_a_cylinder() {
+    let cyl = trtc::Cylinder::default();
+    let examples = [
+        (
+            trtc::point(1.0, 0.0, -5.0),
+            trtc::vector(0.0, 0.0, 1.0),
+            5.0,
+            5.0,
+        ),
+        (
+            trtc::point(0.0, 0.0, -5.0),
+            trtc::vector(0.0, 0.0, 1.0),
+            4.0,
+            6.0,
+        ),
+        (
+            trtc::point(0.5, 0.0, -5.0),
+            trtc::vector(0.1, 1.0, 1.0),
+            6.80798,
+            7.08872,
+        ),
+    ];
+
+    for (origin, direction, t0, t1) in examples {
+        let r = trtc::Ray::new(origin, direction.normalize());
+
+        let xs = cyl.local_intersect(&r);
+
+        assert_eq!(xs.count(), 2);
+        assert!((xs[0].t - t0).abs() < 0.00001);
+        assert!((xs[1].t - t1).abs() < 0.00001);
+    }
+}
+
+#[test]
+fn test_normal_vector_on_a_cylinder() {
+    let cyl = trtc::Cylinder::default();
+    let examples = [
+        (trtc::point(1.0, 0.0, 0.0), trtc::vector(1.0, 0.0, 0.0)),
+        (trtc::point(0.0, 5.0, -1.0), trtc::vector(0.0, 0.0, -1.0)),
+        (trtc::point(0.0, -2.0, 1.0), trtc::vector(0.0, 0.0, 1.0)),
+        (trtc::point(-1.0, 1.0, 0.0), trtc::vector(-1.0, 0.0, 0.0)),
+    ];
+
+    let i = trtc::Intersection::new(0.0, &cyl);
+
+    for (point, normal) in examples {
+        assert_eq!(cyl.local_normal_at(point, &i), normal);
+    }
+}
+
+#[test]
+fn test_the_default_minimum_and_maximum_for_a_cylinder() {
+    let cyl = trtc::Cylinder::default();
+
+    assert_eq!(cyl.minimum, f64::NEG_INFINITY);
+    assert_eq!(cyl.maximum, f64::INFINITY);
+}
+
+#[test]
+fn test_intersecting_a_constrained_cylinder() {
+    let cyl = trtc::Cylinder {
+        minimum: 1.0,
+        maximum: 2.0,
+        ..Default::default()
+    };
+    let examples = [
+        (trtc::point(0.0, 1.5, 0.0), trtc::vector(0.1, 1.0, 0.0), 0),
+        (trtc::point(0.0, 3.0, -5.0), trtc::vector(0.0, 0.0, 1.0), 0),
+        (trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0), 0),
+        (trtc::point(0.0, 2.0, -5.0), trtc::vector(0.0, 0.0, 1.0), 0),
+        (trtc::point(0.0, 1.0, -5.0), trtc::vector(0.0, 0.0, 1.0), 0),
+        (trtc::point(0.0, 1.5, -2.0), trtc::vector(0.0, 0.0, 1.0), 2),
+    ];
+
+    for (point, direction, count) in examples {
+        let r = trtc::Ray::new(point, direction.normalize());
+
+        let xs = cyl.local_intersect(&r);
+
+        assert_eq!(xs.count(), count);
+    }
+}
+
+#[test]
+fn test_the_default_closed_value_for_a_cylinder() {
+    let cyl = trtc::Cylinder::default();
+
+    assert!(!cyl.closed);
+}
+
+#[test]
+fn test_intersecting_the_caps_of_a_closed_cylinder() {
+    let cyl = trtc::Cylinder {
+        minimum: 1.0,
+        maximum: 2.0,
+        closed: true,
+        ..Default::default()
+    };
+    let examples = [
+        (trtc::point(0.0, 3.0, 0.0), trtc::vector(0.0, -1.0, 0.0), 2),
+        (trtc::point(0.0, 3.0, -2.0), trtc::vector(0.0, -1.0, 2.0), 2),
+        (trtc::point(0.0, 4.0, -2.0), trtc::vector(0.0, -1.0, 1.0), 2),
+        (trtc::point(0.0, 0.0, -2.0), trtc::vector(0.0, 1.0, 2.0), 2),
+        (trtc::point(0.0, -1.0, -2.0), trtc::vector(0.0, 1.0, 1.0), 2),
+    ];
+
+    for (point, direction, count) in examples {
+        let r = trtc::Ray::new(point, direction.normalize());
+
+        let xs = cyl.local_intersect(&r);
+
+        assert_eq!(xs.count(), count);
+    }
+}
+
+#[test]
+fn test_the_normal_vector_on_a_cylinders_end_caps() {
+    let cyl = trtc::Cylinder {
+        minimum: 1.0,
+        maximum: 2.0,
+        closed: true,
+        ..Default::default()
+    };
+    let examples = [
+        (trtc::point(0.0, 1.0, 0.0), trtc::vector(0.0, -1.0, 0.0)),
+        (trtc::point(0.5, 1.0, 0.0), trtc::vector(0.0, -1.0, 0.0)),
+        (trtc::point(0.0, 1.0, 0.5), trtc::vector(0.0, -1.0, 0.0)),
+        (trtc::point(0.0, 2.0, 0.0), trtc::vector(0.0, 1.0, 0.0)),
+        (trtc::point(0.5, 2.0, 0.0), trtc::vector(0.0, 1.0, 0.0)),
+        (trtc::point(0.0, 2.0, 0.5), trtc::vector(0.0, 1.0, 0.0)),
+    ];
+
+    let i = trtc::Intersection::new(0.0, &cyl);
+
+    for (point, normal) in examples {
+        assert_eq!(cyl.local_normal_at(point, &i), normal);
+    }
+}
@@ -0,0 +1,161 @@
+use std::f64::consts::PI;
+use trtc::Shape;
+
+#[test]
+fn test_creating_a_new_group() {
+    let g = trtc::Group::default();
+
+    assert_eq!(*g.transform(), trtc::Matrix4::eye());
+    assert!(g.children().is_empty());
+}
+
+#[test]
+fn test_adding_a_child_to_a_group() {
+    let mut g = trtc::Group::new(trtc::translation(1.0, 2.0, 3.0));
+
+    g.add_child(Box::new(trtc::Sphere::default()));
+
+    assert_eq!(g.children().len(), 1);
+    assert_eq!(
+        *g.children()[0].parent_transform(),
+        trtc::translation(1.0, 2.0, 3.0)
+    );
+}
+
+#[test]
+fn test_intersecting_a_ray_with_an_empty_group() {
+    let g = trtc::Group::default();
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = g.local_intersect(&r);
+
+    assert_eq!(xs.count(), 0);
+}
+
+#[test]
+fn test_intersecting_a_ray_with_a_nonempty_group() {
+    let mut g = trtc::Group::default();
+    g.add_child(Box::new(trtc::Sphere::default()));
+    g.add_child(Box::new(trtc::Sphere::new(trtc::translation(
+        0.0, 0.0, -3.0,
+    ))));
+    g.add_child(Box::new(trtc::Sphere::new(trtc::translation(
+        5.0, 0.0, 0.0,
+    ))));
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = g.local_intersect(&r);
+
+    let s1 = g.children()[0].as_ref();
+    let s2 = g.children()[1].as_ref();
+    assert_eq!(xs.count(), 4);
+    assert!(std::ptr::addr_eq(xs[0].object, s2));
+    assert!(std::ptr::addr_eq(xs[1].object, s2));
+    assert!(std::ptr::addr_eq(xs[2].object, s1));
+    assert!(std::ptr::addr_eq(xs[3].object, s1));
+}
+
+#[test]
+fn test_intersecting_a_transformed_group() {
+    let mut g = trtc::Group::new(trtc::scaling(2.0, 2.0, 2.0));
+    g.add_child(Box::new(trtc::Sphere::new(trtc::translation(
+        5.0, 0.0, 0.0,
+    ))));
+    let r = trtc::Ray::new(trtc::point(10.0, 0.0, -10.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = trtc::intersect(&g, &r);
+
+    assert_eq!(xs.count(), 2);
+}
+
+#[test]
+fn test_converting_a_point_from_world_to_object_space() {
+    let mut g2 = trtc::Group::new(trtc::scaling(2.0, 2.0, 2.0));
+    g2.add_child(Box::new(trtc::Sphere::new(trtc::translation(
+        5.0, 0.0, 0.0,
+    ))));
+    let mut g1 = trtc::Group::new(trtc::rotation_y(PI / 2.0));
+    g1.add_child(Box::new(g2));
+    let r = trtc::Ray::new(trtc::point(-10.0, 0.0, -10.0), trtc::vector(1.0, 0.0, 0.0));
+    let xs = trtc::intersect(&g1, &r);
+    let s = xs[0].object;
+
+    let p = trtc::world_to_object(s, trtc::point(-2.0, 0.0, -10.0));
+
+    assert_eq!(p, trtc::point(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn test_converting_a_normal_from_object_to_world_space() {
+    let mut g2 = trtc::Group::new(trtc::scaling(1.0, 2.0, 3.0));
+    g2.add_child(Box::new(trtc::Sphere::new(trtc::translation(
+        5.0, 0.0, 0.0,
+    ))));
+    let mut g1 = trtc::Group::new(trtc::rotation_y(PI / 2.0));
+    g1.add_child(Box::new(g2));
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 0.0, -1.0));
+    let xs = trtc::intersect(&g1, &r);
+    let s = xs[0].object;
+
+    let k = 3.0_f64.sqrt() / 3.0;
+    let n = trtc::normal_to_world(s, trtc::vector(k, k, k));
+
+    assert_eq!(n, trtc::vector(2.0 / 7.0, 3.0 / 7.0, -6.0 / 7.0));
+}
+
+#[test]
+fn test_finding_the_normal_on_a_child_object() {
+    let mut g2 = trtc::Group::new(trtc::scaling(1.0, 2.0, 3.0));
+    g2.add_child(Box::new(trtc::Sphere::new(trtc::translation(
+        5.0, 0.0, 0.0,
+    ))));
+    let mut g1 = trtc::Group::new(trtc::rotation_y(PI / 2.0));
+    g1.add_child(Box::new(g2));
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 0.0, -1.0));
+    let xs = trtc::intersect(&g1, &r);
+    let s = xs[0].object;
+
+    let n = trtc::normal_at(s, trtc::point(1.7321, 1.1547, -5.5774), &xs[0]);
+
+    assert!((n - trtc::vector(0.2857, 0.4286, -0.8571)).magnitude() < 0.0001);
+}
+
+#[test]
+fn test_transforming_a_group_updates_its_descendants() {
+    let mut g2 = trtc::Group::default();
+    g2.add_child(Box::new(trtc::Sphere::default()));
+    let mut g1 = trtc::Group::default();
+    g1.add_child(Box::new(g2));
+
+    g1.set_transform(trtc::translation(0.0, 0.0, 10.0));
+
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 0.0, 1.0));
+    let xs = trtc::intersect(&g1, &r);
+    assert_eq!(xs.count(), 2);
+    assert_eq!(
+        trtc::world_to_object(xs[0].object, trtc::point(0.0, 0.0, 9.0)),
+        trtc::point(0.0, 0.0, -1.0)
+    );
+}
+
+#[test]
+fn test_setting_a_group_material_updates_its_descendants() {
+    let mut g2 = trtc::Group::default();
+    g2.add_child(Box::new(trtc::Sphere::default()));
+    let mut g1 = trtc::Group::default();
+    g1.add_child(Box::new(g2));
+    g1.add_child(Box::new(trtc::Cube::default()));
+    let m = trtc::Material {
+        color: trtc::Color::new(1.0, 0.0, 0.0),
+        ..Default::default()
+    };
+
+    g1.set_material(m.clone());
+
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let xs = trtc::intersect(&g1, &r);
+    assert_eq!(xs.count(), 4);
+    for i in &xs.intersections {
+        assert_eq!(*i.object.material(), m);
+    }
+}
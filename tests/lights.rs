@@ -0,0 +1,421 @@
+use std::f64::consts::PI;
+use trtc::Light;
+
+#[test]
+fn test_a_point_light_has_a_position_and_intensity() {
+    let intensity = trtc::Color::new(1.0, 1.0, 1.0);
+    let position = trtc::point(0.0, 0.0, 0.0);
+
+    let light = trtc::PointLight::new(position, intensity);
+
+    assert_eq!(light.position, position);
+    assert_eq!(light.intensity, intensity);
+}
+
+#[test]
+fn test_creating_an_area_light() {
+    let corner = trtc::point(0.0, 0.0, 0.0);
+    let v1 = trtc::vector(2.0, 0.0, 0.0);
+    let v2 = trtc::vector(0.0, 0.0, 1.0);
+
+    let light = trtc::RectangleLight::new(corner, v1, 4, v2, 2, trtc::Color::new(1.0, 1.0, 1.0));
+
+    assert_eq!(light.corner, corner);
+    assert_eq!(light.uvec, trtc::vector(0.5, 0.0, 0.0));
+    assert_eq!(light.usteps, 4);
+    assert_eq!(light.vvec, trtc::vector(0.0, 0.0, 0.5));
+    assert_eq!(light.vsteps, 2);
+    assert_eq!(light.sample_points(trtc::point(0.0, 5.0, 0.0)).len(), 8);
+}
+
+#[test]
+fn test_finding_a_single_point_on_an_area_light() {
+    let mut light = trtc::RectangleLight::new(
+        trtc::point(0.0, 0.0, 0.0),
+        trtc::vector(2.0, 0.0, 0.0),
+        4,
+        trtc::vector(0.0, 0.0, 1.0),
+        2,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    light.jitter = false;
+
+    let samples = light.sample_points(trtc::point(0.0, 5.0, 0.0));
+
+    assert_eq!(samples[0], trtc::point(0.25, 0.0, 0.25));
+    assert_eq!(samples[1], trtc::point(0.75, 0.0, 0.25));
+    assert_eq!(samples[4], trtc::point(0.25, 0.0, 0.75));
+    assert_eq!(samples[2], trtc::point(1.25, 0.0, 0.25));
+    assert_eq!(samples[7], trtc::point(1.75, 0.0, 0.75));
+}
+
+#[test]
+fn test_jittered_samples_stay_within_their_cells() {
+    let light = trtc::RectangleLight::new(
+        trtc::point(0.0, 0.0, 0.0),
+        trtc::vector(2.0, 0.0, 0.0),
+        4,
+        trtc::vector(0.0, 0.0, 1.0),
+        2,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    let p = trtc::point(0.0, 5.0, 0.0);
+
+    let samples = light.sample_points(p);
+
+    assert_eq!(samples, light.sample_points(p));
+    assert_ne!(samples[0], trtc::point(0.25, 0.0, 0.25));
+    for (i, sample) in samples.iter().enumerate() {
+        let (u, v) = ((i % 4) as f64, (i / 4) as f64);
+
+        assert!(sample.x >= u * 0.5 && sample.x <= (u + 1.0) * 0.5);
+        assert!(sample.z >= v * 0.5 && sample.z <= (v + 1.0) * 0.5);
+        assert_eq!(sample.y, 0.0);
+    }
+}
+
+#[test]
+fn test_sphere_light_samples_face_the_point() {
+    let light = trtc::SphereLight::new(
+        trtc::point(1.0, 2.0, 3.0),
+        0.5,
+        3,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    let p = trtc::point(1.0, 2.0, -7.0);
+
+    let samples = light.sample_points(p);
+
+    assert_eq!(samples.len(), 9);
+    for sample in samples {
+        assert!((sample - light.center).magnitude() <= 0.5);
+        assert!(trtc::epsilon_eq(sample.z, 3.0));
+    }
+}
+
+#[test]
+fn test_area_lights_take_at_least_one_sample_per_axis() {
+    let rect = trtc::RectangleLight::new(
+        trtc::point(0.0, 0.0, 0.0),
+        trtc::vector(2.0, 0.0, 0.0),
+        0,
+        trtc::vector(0.0, 0.0, 1.0),
+        0,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    let sphere = trtc::SphereLight::new(
+        trtc::point(0.0, 0.0, 0.0),
+        1.0,
+        0,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    let p = trtc::point(0.0, 5.0, 0.0);
+
+    assert_eq!((rect.usteps, rect.vsteps), (1, 1));
+    assert_eq!(rect.uvec, trtc::vector(2.0, 0.0, 0.0));
+    assert_eq!(rect.vvec, trtc::vector(0.0, 0.0, 1.0));
+    assert_eq!(rect.samples(p).len(), 1);
+    assert_eq!(sphere.steps, 1);
+    assert_eq!(sphere.samples(p).len(), 1);
+}
+
+#[test]
+fn test_a_point_light_samples_the_direction_and_distance_to_its_position() {
+    let light = trtc::PointLight::new(trtc::point(0.0, 3.0, 4.0), trtc::Color::new(1.0, 1.0, 1.0));
+
+    let samples = light.samples(trtc::point(0.0, 0.0, 0.0));
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].direction, trtc::vector(0.0, 0.6, 0.8));
+    assert_eq!(samples[0].distance, 5.0);
+    assert_eq!(samples[0].intensity, light.intensity);
+}
+
+#[test]
+fn test_area_light_samples_point_at_their_sample_positions() {
+    let mut light = trtc::RectangleLight::new(
+        trtc::point(-1.0, 4.0, -1.0),
+        trtc::vector(2.0, 0.0, 0.0),
+        1,
+        trtc::vector(0.0, 0.0, 2.0),
+        1,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    light.jitter = false;
+
+    let samples = light.samples(trtc::point(0.0, 0.0, 0.0));
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].direction, trtc::vector(0.0, 1.0, 0.0));
+    assert_eq!(samples[0].distance, 4.0);
+}
+
+#[test]
+fn test_a_directional_light_shines_from_infinitely_far_away() {
+    let light = trtc::DirectionalLight::new(
+        trtc::vector(0.0, -2.0, 0.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    let near = light.samples(trtc::point(0.0, 0.0, 0.0));
+    let far = light.samples(trtc::point(100.0, -50.0, 20.0));
+
+    assert_eq!(light.direction, trtc::vector(0.0, -1.0, 0.0));
+    assert_eq!(near, far);
+    assert_eq!(near[0].direction, trtc::vector(0.0, 1.0, 0.0));
+    assert_eq!(near[0].distance, f64::INFINITY);
+    assert_eq!(near[0].intensity, light.intensity);
+}
+
+#[test]
+fn test_a_spotlight_has_a_position_direction_and_cone() {
+    let light = trtc::SpotLight::new(
+        trtc::point(0.0, 10.0, 0.0),
+        trtc::vector(0.0, -3.0, 0.0),
+        PI / 6.0,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    assert_eq!(light.position, trtc::point(0.0, 10.0, 0.0));
+    assert_eq!(light.direction, trtc::vector(0.0, -1.0, 0.0));
+    assert_eq!(light.angle, PI / 6.0);
+    assert_eq!(light.falloff, 1.0);
+}
+
+#[test]
+fn test_a_spotlight_only_illuminates_points_inside_its_cone() {
+    let light = trtc::SpotLight::new(
+        trtc::point(0.0, 10.0, 0.0),
+        trtc::vector(0.0, -1.0, 0.0),
+        PI / 6.0,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    let center = light.samples(trtc::point(0.0, 0.0, 0.0));
+    let outside = light.samples(trtc::point(10.0, 0.0, 0.0));
+
+    assert_eq!(center[0].direction, trtc::vector(0.0, 1.0, 0.0));
+    assert_eq!(center[0].distance, 10.0);
+    assert_eq!(center[0].intensity, trtc::Color::new(1.0, 1.0, 1.0));
+    assert_eq!(outside[0].intensity, trtc::Color::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_a_spotlight_only_adds_ambient_light_inside_its_cone() {
+    let light = trtc::SpotLight::new(
+        trtc::point(0.0, 0.0, 0.0),
+        trtc::vector(0.0, 0.0, 1.0),
+        0.3,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    assert_eq!(
+        light.ambient_intensity(trtc::point(0.0, 0.0, 5.0)),
+        trtc::Color::new(1.0, 1.0, 1.0)
+    );
+    assert_eq!(
+        light.ambient_intensity(trtc::point(0.0, 0.0, -5.0)),
+        trtc::Color::new(0.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        light.ambient_intensity(trtc::point(5.0, 0.0, 1.0)),
+        trtc::Color::new(0.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn test_a_spotlight_falls_off_towards_the_edge_of_its_cone() {
+    let mut light = trtc::SpotLight::new(
+        trtc::point(0.0, 0.0, 0.0),
+        trtc::vector(0.0, 0.0, 1.0),
+        PI / 3.0,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    light.falloff = 2.0;
+    let p = trtc::point(1.0, 0.0, 1.0);
+
+    let intensity = light.samples(p)[0].intensity;
+
+    assert_eq!(intensity, trtc::Color::new(0.5, 0.5, 0.5));
+}
+
+#[test]
+fn test_lights_do_not_attenuate_by_default() {
+    let attenuation = trtc::Attenuation::default();
+
+    assert_eq!(attenuation, trtc::Attenuation::new(1.0, 0.0, 0.0));
+    assert_eq!(attenuation.factor(0.5), 1.0);
+    assert_eq!(attenuation.factor(1000.0), 1.0);
+}
+
+#[test]
+fn test_computing_constant_linear_and_quadratic_attenuation() {
+    let attenuation = trtc::Attenuation::new(1.0, 0.5, 0.25);
+
+    assert_eq!(attenuation.factor(1.0), 1.0 / 1.75);
+    assert_eq!(attenuation.factor(2.0), 1.0 / 3.0);
+    assert_eq!(attenuation.factor(4.0), 1.0 / 7.0);
+}
+
+#[test]
+fn test_attenuation_without_a_positive_denominator_leaves_light_unchanged() {
+    assert_eq!(trtc::Attenuation::new(0.0, 0.0, 0.0).factor(2.0), 1.0);
+    assert_eq!(trtc::Attenuation::new(0.0, 0.0, 0.0).factor(0.0), 1.0);
+    assert_eq!(trtc::Attenuation::new(-1.0, 0.0, 0.0).factor(2.0), 1.0);
+    assert_eq!(trtc::Attenuation::new(0.0, -1.0, 0.0).factor(2.0), 1.0);
+}
+
+#[test]
+fn test_inverse_square_attenuation() {
+    let attenuation = trtc::Attenuation::inverse_square();
+
+    assert_eq!(attenuation.factor(1.0), 1.0);
+    assert_eq!(attenuation.factor(2.0), 0.25);
+    assert_eq!(attenuation.factor(10.0), 0.01);
+}
+
+#[test]
+fn test_attenuation_clamps_distances_below_the_minimum() {
+    let attenuation = trtc::Attenuation::inverse_square();
+    let nearest = attenuation.factor(trtc::MIN_LIGHT_DISTANCE);
+
+    assert!(nearest.is_finite());
+    assert_eq!(attenuation.factor(0.0), nearest);
+    assert_eq!(attenuation.factor(-1.0), nearest);
+    assert_eq!(
+        trtc::Attenuation::new(1.0, 0.5, 0.25).factor(0.0),
+        trtc::Attenuation::new(1.0, 0.5, 0.25).factor(trtc::MIN_LIGHT_DISTANCE)
+    );
+}
+
+#[test]
+fn test_an_attenuated_point_light_dims_with_distance() {
+    let mut light =
+        trtc::PointLight::new(trtc::point(0.0, 0.0, 0.0), trtc::Color::new(4.0, 4.0, 4.0));
+    light.attenuation = trtc::Attenuation::inverse_square();
+
+    let near = light.samples(trtc::point(0.0, 1.0, 0.0));
+    let far = light.samples(trtc::point(0.0, 2.0, 0.0));
+
+    assert_eq!(near[0].intensity, trtc::Color::new(4.0, 4.0, 4.0));
+    assert_eq!(far[0].intensity, trtc::Color::new(1.0, 1.0, 1.0));
+    assert_eq!(
+        light.ambient_intensity(trtc::point(0.0, 2.0, 0.0)),
+        trtc::Color::new(1.0, 1.0, 1.0)
+    );
+}
+
+#[test]
+fn test_a_point_light_from_lumens_uses_candela_and_inverse_square_falloff() {
+    let light = trtc::PointLight::from_lumens(
+        trtc::point(0.0, 0.0, 0.0),
+        trtc::Color::new(1.0, 0.5, 0.25),
+        400.0 * PI,
+    );
+
+    let samples = light.samples(trtc::point(10.0, 0.0, 0.0));
+
+    assert_eq!(light.intensity, trtc::Color::new(100.0, 50.0, 25.0));
+    assert_eq!(light.attenuation, trtc::Attenuation::inverse_square());
+    assert_eq!(samples[0].intensity, trtc::Color::new(1.0, 0.5, 0.25));
+}
+
+#[test]
+fn test_a_spotlight_from_lumens_concentrates_flux_in_its_cone() {
+    let light = trtc::SpotLight::from_lumens(
+        trtc::point(0.0, 0.0, 0.0),
+        trtc::vector(0.0, -1.0, 0.0),
+        PI / 3.0,
+        trtc::Color::new(1.0, 1.0, 1.0),
+        100.0 * PI,
+    );
+
+    let samples = light.samples(trtc::point(0.0, -10.0, 0.0));
+
+    assert_eq!(light.intensity, trtc::Color::new(100.0, 100.0, 100.0));
+    assert_eq!(samples[0].intensity, trtc::Color::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_area_lights_attenuate_each_sample_by_its_distance() {
+    let mut light = trtc::RectangleLight::new(
+        trtc::point(-1.0, 2.0, -1.0),
+        trtc::vector(2.0, 0.0, 0.0),
+        1,
+        trtc::vector(0.0, 0.0, 2.0),
+        1,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    light.jitter = false;
+    light.attenuation = trtc::Attenuation::inverse_square();
+
+    let samples = light.samples(trtc::point(0.0, 0.0, 0.0));
+
+    assert_eq!(samples[0].intensity, trtc::Color::new(0.25, 0.25, 0.25));
+}
+
+#[test]
+fn test_directional_lights_are_not_attenuated() {
+    let light = trtc::DirectionalLight::new(
+        trtc::vector(0.0, -1.0, 0.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    assert_eq!(
+        light.ambient_intensity(trtc::point(0.0, -1000.0, 0.0)),
+        light.intensity
+    );
+}
+
+#[test]
+fn test_area_lights_from_lumens_spread_their_flux_over_their_samples() {
+    let color = trtc::Color::new(1.0, 0.5, 0.25);
+    let mut rect = trtc::RectangleLight::from_lumens(
+        trtc::point(-0.5, 0.0, -0.5),
+        trtc::vector(1.0, 0.0, 0.0),
+        2,
+        trtc::vector(0.0, 0.0, 1.0),
+        2,
+        color,
+        400.0 * PI,
+    );
+    rect.jitter = false;
+    let sphere =
+        trtc::SphereLight::from_lumens(trtc::point(0.0, 0.0, 0.0), 0.5, 2, color, 400.0 * PI);
+    let point = trtc::PointLight::from_lumens(trtc::point(0.0, 0.0, 0.0), color, 400.0 * PI);
+    let p = trtc::point(0.0, 100.0, 0.0);
+    let expected = point.samples(p)[0].intensity;
+
+    for light in [&rect as &dyn trtc::Light, &sphere] {
+        let samples = light.samples(p);
+        let mean = samples
+            .iter()
+            .fold(trtc::Color::default(), |acc, s| acc + s.intensity)
+            * (1.0 / samples.len() as f64);
+
+        assert_eq!(samples.len(), 4);
+        assert!(trtc::epsilon_eq(mean.r, expected.r));
+        assert!(trtc::epsilon_eq(mean.g, expected.g));
+        assert!(trtc::epsilon_eq(mean.b, expected.b));
+    }
+    assert_eq!(rect.intensity, trtc::Color::new(100.0, 50.0, 25.0));
+    assert_eq!(rect.attenuation, trtc::Attenuation::inverse_square());
+    assert_eq!(sphere.intensity, trtc::Color::new(100.0, 50.0, 25.0));
+    assert_eq!(sphere.attenuation, trtc::Attenuation::inverse_square());
+}
+
+#[test]
+fn test_a_directional_light_from_lux_ignores_distance() {
+    let light = trtc::DirectionalLight::from_lux(
+        trtc::vector(0.0, -1.0, 0.0),
+        trtc::Color::new(1.0, 0.5, 0.25),
+        4.0,
+    );
+
+    let near = light.samples(trtc::point(0.0, 0.0, 0.0));
+    let far = light.samples(trtc::point(0.0, -1000.0, 0.0));
+
+    assert_eq!(light.intensity, trtc::Color::new(4.0, 2.0, 1.0));
+    assert_eq!(near[0].intensity, light.intensity);
+    assert_eq!(far[0].intensity, light.intensity);
+}
@@ -0,0 +1,240 @@
+#[test]
+fn test_the_default_material() {
+    let m = trtc::Material::default();
+
+    assert_eq!(m.color, trtc::Color::new(1.0, 1.0, 1.0));
+    assert_eq!(m.ambient, 0.1);
+    assert_eq!(m.diffuse, 0.9);
+    assert_eq!(m.specular, 0.9);
+    assert_eq!(m.shininess, 200.0);
+}
+
+#[test]
+fn test_lighting_with_the_eye_between_the_light_and_the_surface() {
+    let m = trtc::Material::default();
+    let object = trtc::Sphere::default();
+    let position = trtc::point(0.0, 0.0, 0.0);
+
+    let eyev = trtc::vector(0.0, 0.0, -1.0);
+    let normalv = trtc::vector(0.0, 0.0, -1.0);
+    let light = trtc::PointLight::new(
+        trtc::point(0.0, 0.0, -10.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    let result = trtc::lighting(&m, &object, &light, position, eyev, normalv, 1.0);
+
+    assert_eq!(result, trtc::Color::new(1.9, 1.9, 1.9));
+}
+
+#[test]
+fn test_lighting_with_the_eye_between_light_and_surface_eye_offset_45_degrees() {
+    let m = trtc::Material::default();
+    let object = trtc::Sphere::default();
+    let position = trtc::point(0.0, 0.0, 0.0);
+
+    let k = 2.0_f64.sqrt() / 2.0;
+    let eyev = trtc::vector(0.0, k, -k);
+    let normalv = trtc::vector(0.0, 0.0, -1.0);
+    let light = trtc::PointLight::new(
+        trtc::point(0.0, 0.0, -10.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    let result = trtc::lighting(&m, &object, &light, position, eyev, normalv, 1.0);
+
+    assert_eq!(result, trtc::Color::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_lighting_with_eye_opposite_surface_light_offset_45_degrees() {
+    let m = trtc::Material::default();
+    let object = trtc::Sphere::default();
+    let position = trtc::point(0.0, 0.0, 0.0);
+
+    let eyev = trtc::vector(0.0, 0.0, -1.0);
+    let normalv = trtc::vector(0.0, 0.0, -1.0);
+    let light = trtc::PointLight::new(
+        trtc::point(0.0, 10.0, -10.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    let result = trtc::lighting(&m, &object, &light, position, eyev, normalv, 1.0);
+
+    assert_eq!(result, trtc::Color::new(0.7364, 0.7364, 0.7364));
+}
+
+#[test]
+fn test_lighting_with_eye_in_the_path_of_the_reflection_vector() {
+    let m = trtc::Material::default();
+    let object = trtc::Sphere::default();
+    let position = trtc::point(0.0, 0.0, 0.0);
+
+    let k = 2.0_f64.sqrt() / 2.0;
+    let eyev = trtc::vector(0.0, -k, -k);
+    let normalv = trtc::vector(0.0, 0.0, -1.0);
+    let light = trtc::PointLight::new(
+        trtc::point(0.0, 10.0, -10.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    let result = trtc::lighting(&m, &object, &light, position, eyev, normalv, 1.0);
+
+    assert_eq!(result, trtc::Color::new(1.6364, 1.6364, 1.6364));
+}
+
+#[test]
+fn test_lighting_with_the_light_behind_the_surface() {
+    let m = trtc::Material::default();
+    let object = trtc::Sphere::default();
+    let position = trtc::point(0.0, 0.0, 0.0);
+
+    let eyev = trtc::vector(0.0, 0.0, -1.0);
+    let normalv = trtc::vector(0.0, 0.0, -1.0);
+    let light = trtc::PointLight::new(trtc::point(0.0, 0.0, 10.0), trtc::Color::new(1.0, 1.0, 1.0));
+
+    let result = trtc::lighting(&m, &object, &light, position, eyev, normalv, 1.0);
+
+    assert_eq!(result, trtc::Color::new(0.1, 0.1, 0.1));
+}
+
+#[test]
+fn test_lighting_with_the_surface_in_shadow() {
+    let m = trtc::Material::default();
+    let object = trtc::Sphere::default();
+    let position = trtc::point(0.0, 0.0, 0.0);
+
+    let eyev = trtc::vector(0.0, 0.0, -1.0);
+    let normalv = trtc::vector(0.0, 0.0, -1.0);
+    let light = trtc::PointLight::new(
+        trtc::point(0.0, 0.0, -10.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    let light_intensity = 0.0;
+
+    let result = trtc::lighting(
+        &m,
+        &object,
+        &light,
+        position,
+        eyev,
+        normalv,
+        light_intensity,
+    );
+
+    assert_eq!(result, trtc::Color::new(0.1, 0.1, 0.1));
+}
+
+#[test]
+fn test_reflectivity_for_the_default_material() {
+    let m = trtc::Material::default();
+
+    assert_eq!(m.reflective, 0.0);
+}
+
+#[test]
+fn test_transparency_and_refractive_index_for_the_default_material() {
+    let m = trtc::Material::default();
+
+    assert_eq!(m.transparency, 0.0);
+    assert_eq!(m.refractive_index, 1.0);
+}
+
+#[test]
+fn test_lighting_uses_light_intensity_to_attenuate_color() {
+    let m = trtc::Material {
+        ambient: 0.1,
+        diffuse: 0.9,
+        specular: 0.0,
+        ..Default::default()
+    };
+    let object = trtc::Sphere::default();
+    let light = trtc::PointLight::new(
+        trtc::point(0.0, 0.0, -10.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    let p = trtc::point(0.0, 0.0, -1.0);
+    let eyev = trtc::vector(0.0, 0.0, -1.0);
+    let normalv = trtc::vector(0.0, 0.0, -1.0);
+    let examples = [
+        (1.0, trtc::Color::new(1.0, 1.0, 1.0)),
+        (0.5, trtc::Color::new(0.55, 0.55, 0.55)),
+        (0.0, trtc::Color::new(0.1, 0.1, 0.1)),
+    ];
+
+    for (intensity, expected) in examples {
+        let result = trtc::lighting(&m, &object, &light, p, eyev, normalv, intensity);
+
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn test_lighting_samples_the_area_light() {
+    let mut light = trtc::RectangleLight::new(
+        trtc::point(-0.5, -0.5, -5.0),
+        trtc::vector(1.0, 0.0, 0.0),
+        2,
+        trtc::vector(0.0, 1.0, 0.0),
+        2,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    light.jitter = false;
+    let mut shape = trtc::Sphere::default();
+    shape.material.ambient = 0.1;
+    shape.material.diffuse = 0.9;
+    shape.material.specular = 0.0;
+    let eye = trtc::point(0.0, 0.0, -5.0);
+    let examples = [
+        (
+            trtc::point(0.0, 0.0, -1.0),
+            trtc::Color::new(0.9965, 0.9965, 0.9965),
+        ),
+        (
+            trtc::point(
+                0.0,
+                std::f64::consts::FRAC_1_SQRT_2,
+                -std::f64::consts::FRAC_1_SQRT_2,
+            ),
+            trtc::Color::new(0.62318, 0.62318, 0.62318),
+        ),
+    ];
+
+    for (p, expected) in examples {
+        let eyev = (eye - p).normalize();
+        let normalv = trtc::vector(p.x, p.y, p.z);
+        let result = trtc::lighting(&shape.material, &shape, &light, p, eyev, normalv, 1.0);
+
+        assert!((result.r - expected.r).abs() < 0.0001);
+        assert!((result.g - expected.g).abs() < 0.0001);
+        assert!((result.b - expected.b).abs() < 0.0001);
+    }
+}
+
+#[test]
+fn test_lighting_with_an_inverse_square_light_depends_on_distance() {
+    let m = trtc::Material::default();
+    let object = trtc::Sphere::default();
+    let eyev = trtc::vector(0.0, 0.0, -1.0);
+    let normalv = trtc::vector(0.0, 0.0, -1.0);
+    let position = trtc::point(0.0, 0.0, 0.0);
+    let mut light = trtc::PointLight::new(
+        trtc::point(0.0, 0.0, -10.0),
+        trtc::Color::new(100.0, 100.0, 100.0),
+    );
+    light.attenuation = trtc::Attenuation::inverse_square();
+    let reference = trtc::PointLight::new(
+        trtc::point(0.0, 0.0, -10.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    let near = trtc::lighting(&m, &object, &light, position, eyev, normalv, 1.0);
+    light.position = trtc::point(0.0, 0.0, -20.0);
+    let far = trtc::lighting(&m, &object, &light, position, eyev, normalv, 1.0);
+
+    assert_eq!(
+        near,
+        trtc::lighting(&m, &object, &reference, position, eyev, normalv, 1.0)
+    );
+    assert_eq!(far, near * 0.25);
+}
@@ -1,5 +1,3 @@
-use trtc;
-
 #[test]
 fn test_constructing_and_inspecting_a_4x4_matrix() {
     #[rustfmt::skip]
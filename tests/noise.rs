@@ -0,0 +1,98 @@
+#[test]
+#[allow(clippy::approx_constant)]
+fn test_perlin_noise_matches_the_reference_implementation() {
+    let n = trtc::perlin_noise(trtc::point(3.14, 42.0, 7.0));
+
+    assert!((n - 0.13691995878400012).abs() < 1e-12);
+}
+
+#[test]
+fn test_perlin_noise_is_zero_on_integer_lattice_points() {
+    assert_eq!(trtc::perlin_noise(trtc::point(0.0, 0.0, 0.0)), 0.0);
+    assert_eq!(trtc::perlin_noise(trtc::point(1.0, -2.0, 3.0)), 0.0);
+    assert_eq!(trtc::perlin_noise(trtc::point(-7.0, 12.0, 300.0)), 0.0);
+}
+
+#[test]
+fn test_perlin_noise_handles_huge_coordinates() {
+    let huge = [1e19, -1e19, f64::MAX, f64::MIN];
+
+    for c in huge {
+        let n = trtc::perlin_noise(trtc::point(c, c, c));
+
+        assert!((-1.0..=1.0).contains(&n));
+    }
+    assert!(
+        (trtc::perlin_noise(trtc::point(256.5, 512.25, -255.75))
+            - trtc::perlin_noise(trtc::point(0.5, 0.25, 0.25)))
+        .abs()
+            < 1e-12
+    );
+}
+
+#[test]
+fn test_perlin_noise_stays_within_unit_range() {
+    for i in 0..1000 {
+        let t = i as f64 * 0.137;
+        let n = trtc::perlin_noise(trtc::point(t, t * 0.5 - 3.0, -t * 1.3));
+
+        assert!((-1.0..=1.0).contains(&n));
+    }
+}
+
+#[test]
+fn test_the_default_generator_uses_the_reference_permutation() {
+    let p = trtc::point(1.7, -0.3, 12.45);
+
+    assert_eq!(trtc::Perlin::default().noise(p), trtc::perlin_noise(p));
+}
+
+#[test]
+fn test_noise_is_deterministic_for_a_seed() {
+    let a = trtc::Perlin::new(42);
+    let b = trtc::Perlin::new(42);
+    let p = trtc::point(0.4, 1.9, -2.2);
+
+    assert_eq!(a, b);
+    assert_eq!(a.noise(p), b.noise(p));
+}
+
+#[test]
+fn test_different_seeds_produce_different_noise() {
+    let a = trtc::Perlin::new(1);
+    let b = trtc::Perlin::new(2);
+    let p = trtc::point(0.4, 1.9, -2.2);
+
+    assert_ne!(a, b);
+    assert_ne!(a.noise(p), b.noise(p));
+}
+
+#[test]
+fn test_a_single_octave_of_fbm_is_plain_noise() {
+    let perlin = trtc::Perlin::new(7);
+    let p = trtc::point(0.4, 1.9, -2.2);
+
+    assert_eq!(perlin.fbm(p, 1, 2.0, 0.5), perlin.noise(p));
+}
+
+#[test]
+fn test_fbm_adds_octaves_of_increasing_frequency() {
+    let perlin = trtc::Perlin::new(7);
+    let p = trtc::point(0.4, 1.9, -2.2);
+
+    let expected = (perlin.noise(p) + perlin.noise(trtc::point(0.8, 3.8, -4.4)) * 0.5) / 1.5;
+
+    assert!((perlin.fbm(p, 2, 2.0, 0.5) - expected).abs() < 1e-12);
+}
+
+#[test]
+fn test_turbulence_is_never_negative() {
+    let perlin = trtc::Perlin::new(3);
+
+    for i in 0..500 {
+        let t = i as f64 * 0.173;
+        let n = perlin.turbulence(trtc::point(t, -t * 0.7, t * 0.3 + 1.0), 4);
+
+        assert!((0.0..=1.0).contains(&n));
+    }
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use common::SolidUv;
+use std::rc::Rc;
+
+fn flat(_p: trtc::Tuple4) -> f64 {
+    0.25
+}
+
+fn ramp(p: trtc::Tuple4) -> f64 {
+    p.x
+}
+
+fn plane_with(normal_map: Rc<dyn trtc::NormalMap>) -> trtc::Plane {
+    let mut plane = trtc::Plane::default();
+    plane.material.normal_map = Some(normal_map);
+    plane
+}
+
+fn normal_on(shape: &dyn trtc::Shape, p: trtc::Tuple4) -> trtc::Tuple4 {
+    let i = trtc::Intersection::new(1.0, shape);
+    trtc::normal_at(shape, p, &i)
+}
+
+#[test]
+fn test_the_default_material_has_no_normal_map() {
+    let m = trtc::Material::default();
+
+    assert!(m.normal_map.is_none());
+}
+
+#[test]
+fn test_a_constant_height_field_leaves_the_normal_unchanged() {
+    let height: fn(trtc::Tuple4) -> f64 = flat;
+    let plane = plane_with(Rc::new(trtc::BumpMap::new(height, 1.0)));
+
+    let n = normal_on(&plane, trtc::point(0.3, 0.0, -2.1));
+
+    assert_eq!(n, trtc::vector(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_a_sloped_height_field_tilts_the_normal_against_the_slope() {
+    let height: fn(trtc::Tuple4) -> f64 = ramp;
+    let plane = plane_with(Rc::new(trtc::BumpMap::new(height, 0.5)));
+
+    let n = normal_on(&plane, trtc::point(0.3, 0.0, -2.1));
+
+    assert_eq!(n, trtc::vector(-0.5, 1.0, 0.0).normalize());
+}
+
+#[test]
+fn test_bump_mapping_is_applied_in_object_space() {
+    let height: fn(trtc::Tuple4) -> f64 = ramp;
+    let mut plane = plane_with(Rc::new(trtc::BumpMap::new(height, 1.0)));
+    plane.transform = trtc::rotation_y(std::f64::consts::PI / 2.0);
+
+    let n = normal_on(&plane, trtc::point(0.0, 0.0, 0.0));
+
+    assert_eq!(n, trtc::vector(0.0, 1.0, 1.0).normalize());
+}
+
+#[test]
+fn test_a_noise_bump_map_perturbs_the_normal_of_a_sphere() {
+    let mut sphere = trtc::Sphere::default();
+    sphere.material.normal_map = Some(Rc::new(trtc::BumpMap::new(trtc::Perlin::new(1), 0.3)));
+    let p = trtc::point(0.6, 0.0, 0.8);
+
+    let n = normal_on(&sphere, p);
+
+    assert_ne!(n, trtc::vector(0.6, 0.0, 0.8));
+    assert!(trtc::epsilon_eq(n.magnitude(), 1.0));
+    assert!(n.dot(&trtc::vector(0.6, 0.0, 0.8)) > 0.0);
+}
+
+#[test]
+fn test_a_flat_normal_map_leaves_the_normal_unchanged() {
+    let texture = trtc::NormalMapTexture::new(
+        SolidUv(trtc::Color::new(0.5, 0.5, 1.0)),
+        trtc::UvMapping::Spherical,
+    );
+    let mut sphere = trtc::Sphere::default();
+    sphere.material.normal_map = Some(Rc::new(texture));
+
+    let n = normal_on(&sphere, trtc::point(0.0, 0.6, -0.8));
+
+    assert_eq!(n, trtc::vector(0.0, 0.6, -0.8));
+}
+
+#[test]
+fn test_a_normal_map_tilts_the_normal_along_the_texture_axes() {
+    let along_u = trtc::NormalMapTexture::new(
+        SolidUv(trtc::Color::new(1.0, 0.5, 0.5)),
+        trtc::UvMapping::Planar,
+    );
+    let along_v = trtc::NormalMapTexture::new(
+        SolidUv(trtc::Color::new(0.5, 1.0, 0.5)),
+        trtc::UvMapping::Planar,
+    );
+
+    let nu = normal_on(&plane_with(Rc::new(along_u)), trtc::point(0.3, 0.0, 0.4));
+    let nv = normal_on(&plane_with(Rc::new(along_v)), trtc::point(0.3, 0.0, 0.4));
+
+    assert_eq!(nu, trtc::vector(1.0, 0.0, 0.0));
+    assert_eq!(nv, trtc::vector(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_a_normal_map_follows_u_around_a_sphere() {
+    let texture = trtc::NormalMapTexture::new(
+        SolidUv(trtc::Color::new(1.0, 0.5, 0.5)),
+        trtc::UvMapping::Spherical,
+    );
+    let mut sphere = trtc::Sphere::default();
+    sphere.material.normal_map = Some(Rc::new(texture));
+
+    let n = normal_on(&sphere, trtc::point(0.0, 0.0, -1.0));
+
+    assert_eq!(n, trtc::vector(1.0, 0.0, 0.0));
+}
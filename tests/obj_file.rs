@@ -0,0 +1,336 @@
+fn flat(face: &trtc::Face) -> &trtc::Triangle {
+    match face {
+        trtc::Face::Flat(t) => t,
+        trtc::Face::Smooth(_) => panic!("expected a flat triangle"),
+    }
+}
+
+fn smooth(face: &trtc::Face) -> &trtc::SmoothTriangle {
+    match face {
+        trtc::Face::Smooth(t) => t,
+        trtc::Face::Flat(_) => panic!("expected a smooth triangle"),
+    }
+}
+
+#[test]
+fn test_ignoring_unrecognized_lines() {
+    let gibberish = "There was a young lady named Bright
+who traveled much faster than light.
+She set out one day
+in a relative way,
+and came back the previous night.
+";
+
+    let parser = trtc::parse_obj_file(gibberish).unwrap();
+
+    assert_eq!(parser.ignored, 5);
+}
+
+#[test]
+fn test_vertex_records() {
+    let file = "v -1 1 0
+v -1.0000 0.5000 0.0000
+v 1 0 0
+v 1 1 0
+";
+
+    let parser = trtc::parse_obj_file(file).unwrap();
+
+    assert_eq!(parser.vertices[0], trtc::point(-1.0, 1.0, 0.0));
+    assert_eq!(parser.vertices[1], trtc::point(-1.0, 0.5, 0.0));
+    assert_eq!(parser.vertices[2], trtc::point(1.0, 0.0, 0.0));
+    assert_eq!(parser.vertices[3], trtc::point(1.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_parsing_triangle_faces() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+f 1 3 4
+";
+
+    let parser = trtc::parse_obj_file(file).unwrap();
+    let g = &parser.default_group;
+    let t1 = flat(&g.faces[0]);
+    let t2 = flat(&g.faces[1]);
+
+    assert_eq!(t1.p1, parser.vertices[0]);
+    assert_eq!(t1.p2, parser.vertices[1]);
+    assert_eq!(t1.p3, parser.vertices[2]);
+    assert_eq!(t2.p1, parser.vertices[0]);
+    assert_eq!(t2.p2, parser.vertices[2]);
+    assert_eq!(t2.p3, parser.vertices[3]);
+}
+
+#[test]
+fn test_triangulating_polygons() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+v 0 2 0
+
+f 1 2 3 4 5
+";
+
+    let parser = trtc::parse_obj_file(file).unwrap();
+    let g = &parser.default_group;
+
+    assert_eq!(g.faces.len(), 3);
+    for (i, face) in g.faces.iter().enumerate() {
+        let t = flat(face);
+        assert_eq!(t.p1, parser.vertices[0]);
+        assert_eq!(t.p2, parser.vertices[i + 1]);
+        assert_eq!(t.p3, parser.vertices[i + 2]);
+    }
+}
+
+#[test]
+fn test_triangles_in_groups() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4
+";
+
+    let parser = trtc::parse_obj_file(file).unwrap();
+    let t1 = flat(&parser.group("FirstGroup").unwrap().faces[0]);
+    let t2 = flat(&parser.group("SecondGroup").unwrap().faces[0]);
+
+    assert!(parser.default_group.faces.is_empty());
+    assert_eq!(t1.p1, parser.vertices[0]);
+    assert_eq!(t1.p2, parser.vertices[1]);
+    assert_eq!(t1.p3, parser.vertices[2]);
+    assert_eq!(t2.p1, parser.vertices[0]);
+    assert_eq!(t2.p2, parser.vertices[2]);
+    assert_eq!(t2.p3, parser.vertices[3]);
+}
+
+#[test]
+fn test_reopening_a_group_appends_to_it() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4
+g FirstGroup
+f 2 3 4
+";
+
+    let parser = trtc::parse_obj_file(file).unwrap();
+
+    assert_eq!(parser.groups.len(), 2);
+    assert_eq!(parser.group("FirstGroup").unwrap().faces.len(), 2);
+}
+
+#[test]
+fn test_vertex_normal_records() {
+    let file = "vn 0 0 1
+vn 0.707 0 -0.707
+vn 1 2 3
+";
+
+    let parser = trtc::parse_obj_file(file).unwrap();
+
+    assert_eq!(parser.normals[0], trtc::vector(0.0, 0.0, 1.0));
+    assert_eq!(parser.normals[1], trtc::vector(0.707, 0.0, -0.707));
+    assert_eq!(parser.normals[2], trtc::vector(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_texture_coordinate_records() {
+    let file = "vt 0.25 0.75
+vt 0.5
+";
+
+    let parser = trtc::parse_obj_file(file).unwrap();
+
+    assert_eq!(
+        parser.texture_coords[0],
+        trtc::Tuple2::from_array([0.25, 0.75])
+    );
+    assert_eq!(
+        parser.texture_coords[1],
+        trtc::Tuple2::from_array([0.5, 0.0])
+    );
+}
+
+#[test]
+fn test_faces_with_normals() {
+    let file = "v 0 1 0
+v -1 0 0
+v 1 0 0
+
+vt 0 0
+
+vn -1 0 0
+vn 1 0 0
+vn 0 1 0
+
+f 1//3 2//1 3//2
+f 1/1/3 2/1/1 3/1/2
+";
+
+    let parser = trtc::parse_obj_file(file).unwrap();
+    let g = &parser.default_group;
+    let t1 = smooth(&g.faces[0]);
+    let t2 = smooth(&g.faces[1]);
+
+    assert_eq!(t1.p1, parser.vertices[0]);
+    assert_eq!(t1.p2, parser.vertices[1]);
+    assert_eq!(t1.p3, parser.vertices[2]);
+    assert_eq!(t1.n1, parser.normals[2]);
+    assert_eq!(t1.n2, parser.normals[0]);
+    assert_eq!(t1.n3, parser.normals[1]);
+    assert_eq!(t1.uvs, None);
+    assert_eq!((t2.p1, t2.p2, t2.p3), (t1.p1, t1.p2, t1.p3));
+    assert_eq!((t2.n1, t2.n2, t2.n3), (t1.n1, t1.n2, t1.n3));
+    assert_eq!(t2.uvs, Some([parser.texture_coords[0]; 3]));
+}
+
+#[test]
+fn test_faces_with_texture_coordinates() {
+    let file = "v 0 1 0
+v -1 0 0
+v 1 0 0
+v 0 -1 0
+
+vt 0.5 1
+vt 0 0.5
+vt 1 0.5
+vt 0.5 0
+
+f 1/1 2/2 3/3 4/4
+f 1/1 2 3/3
+";
+
+    let parser = trtc::parse_obj_file(file).unwrap();
+    let g = &parser.default_group;
+    let t1 = flat(&g.faces[0]);
+    let t2 = flat(&g.faces[1]);
+    let t3 = flat(&g.faces[2]);
+
+    assert_eq!(
+        t1.uvs,
+        Some([
+            parser.texture_coords[0],
+            parser.texture_coords[1],
+            parser.texture_coords[2],
+        ])
+    );
+    assert_eq!(
+        t2.uvs,
+        Some([
+            parser.texture_coords[0],
+            parser.texture_coords[2],
+            parser.texture_coords[3],
+        ])
+    );
+    assert_eq!(t3.uvs, None);
+}
+
+#[test]
+fn test_negative_indices_are_relative_to_the_end() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+
+f -3 -2 -1
+";
+
+    let parser = trtc::parse_obj_file(file).unwrap();
+    let t = flat(&parser.default_group.faces[0]);
+
+    assert_eq!(t.p1, parser.vertices[0]);
+    assert_eq!(t.p3, parser.vertices[2]);
+}
+
+#[test]
+fn test_malformed_vertex_reports_its_line() {
+    let file = "v 1 0 0
+v 1 zero 0
+";
+
+    let err = trtc::parse_obj_file(file).unwrap_err();
+
+    assert!(matches!(err, trtc::ObjError::Parse { line: 2, .. }));
+}
+
+#[test]
+fn test_non_finite_numbers_report_their_line() {
+    let records = ["v 1 0 nan", "vn inf 0 0", "vt 0.5 -infinity", "v NaN 1 1"];
+
+    for record in records {
+        let file = format!("v 1 0 0\n{}\n", record);
+
+        let err = trtc::parse_obj_file(&file).unwrap_err();
+
+        assert!(matches!(err, trtc::ObjError::Parse { line: 2, .. }));
+    }
+}
+
+#[test]
+fn test_face_referencing_a_missing_vertex_reports_its_line() {
+    let file = "v 1 0 0
+v 0 1 0
+v 0 0 1
+# a comment
+
+f 1 2 4
+";
+
+    let err = trtc::parse_obj_file(file).unwrap_err();
+
+    assert!(matches!(err, trtc::ObjError::Parse { line: 6, .. }));
+    assert_eq!(err.to_string(), "line 6: vertex index 4 out of range");
+}
+
+#[test]
+fn test_face_with_too_few_vertices_is_an_error() {
+    let file = "v 1 0 0
+v 0 1 0
+f 1 2
+";
+
+    let err = trtc::parse_obj_file(file).unwrap_err();
+
+    assert!(matches!(err, trtc::ObjError::Parse { line: 3, .. }));
+}
+
+#[test]
+fn test_converting_an_obj_file_to_a_group() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4
+";
+    let parser = trtc::parse_obj_file(file).unwrap();
+
+    let g = parser.to_group();
+
+    assert_eq!(g.children().len(), 3);
+
+    let r = trtc::Ray::new(trtc::point(0.5, 0.75, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let xs = trtc::intersect(&g, &r);
+    assert_eq!(xs.count(), 1);
+    assert_eq!(xs[0].t, 5.0);
+}
@@ -0,0 +1,305 @@
+mod common;
+
+use common::{TestPattern, black, white};
+use std::rc::Rc;
+use trtc::Pattern;
+
+#[test]
+fn test_creating_a_stripe_pattern() {
+    let pattern = trtc::StripePattern::new(white(), black());
+
+    assert_eq!(pattern.a, white());
+    assert_eq!(pattern.b, black());
+}
+
+#[test]
+fn test_a_stripe_pattern_is_constant_in_y() {
+    let pattern = trtc::StripePattern::new(white(), black());
+
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 1.0, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 2.0, 0.0)), white());
+}
+
+#[test]
+fn test_a_stripe_pattern_is_constant_in_z() {
+    let pattern = trtc::StripePattern::new(white(), black());
+
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 1.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 2.0)), white());
+}
+
+#[test]
+fn test_a_stripe_pattern_alternates_in_x() {
+    let pattern = trtc::StripePattern::new(white(), black());
+
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(0.9, 0.0, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(1.0, 0.0, 0.0)), black());
+    assert_eq!(pattern.pattern_at(trtc::point(-0.1, 0.0, 0.0)), black());
+    assert_eq!(pattern.pattern_at(trtc::point(-1.0, 0.0, 0.0)), black());
+    assert_eq!(pattern.pattern_at(trtc::point(-1.1, 0.0, 0.0)), white());
+}
+
+#[test]
+fn test_lighting_with_a_pattern_applied() {
+    let m = trtc::Material {
+        pattern: Some(Rc::new(trtc::StripePattern::new(white(), black()))),
+        ambient: 1.0,
+        diffuse: 0.0,
+        specular: 0.0,
+        ..Default::default()
+    };
+    let object = trtc::Sphere::default();
+    let eyev = trtc::vector(0.0, 0.0, -1.0);
+    let normalv = trtc::vector(0.0, 0.0, -1.0);
+    let light = trtc::PointLight::new(trtc::point(0.0, 0.0, -10.0), white());
+
+    let c1 = trtc::lighting(
+        &m,
+        &object,
+        &light,
+        trtc::point(0.9, 0.0, 0.0),
+        eyev,
+        normalv,
+        1.0,
+    );
+    let c2 = trtc::lighting(
+        &m,
+        &object,
+        &light,
+        trtc::point(1.1, 0.0, 0.0),
+        eyev,
+        normalv,
+        1.0,
+    );
+
+    assert_eq!(c1, white());
+    assert_eq!(c2, black());
+}
+
+#[test]
+fn test_stripes_with_an_object_transformation() {
+    let object = trtc::Sphere::new(trtc::scaling(2.0, 2.0, 2.0));
+    let pattern = trtc::StripePattern::new(white(), black());
+
+    let c = trtc::pattern_at_shape(&pattern, &object, trtc::point(1.5, 0.0, 0.0));
+
+    assert_eq!(c, white());
+}
+
+#[test]
+fn test_stripes_with_a_pattern_transformation() {
+    let object = trtc::Sphere::default();
+    let mut pattern = trtc::StripePattern::new(white(), black());
+    pattern.set_transform(trtc::scaling(2.0, 2.0, 2.0));
+
+    let c = trtc::pattern_at_shape(&pattern, &object, trtc::point(1.5, 0.0, 0.0));
+
+    assert_eq!(c, white());
+}
+
+#[test]
+fn test_stripes_with_both_an_object_and_a_pattern_transformation() {
+    let object = trtc::Sphere::new(trtc::scaling(2.0, 2.0, 2.0));
+    let mut pattern = trtc::StripePattern::new(white(), black());
+    pattern.set_transform(trtc::translation(0.5, 0.0, 0.0));
+
+    let c = trtc::pattern_at_shape(&pattern, &object, trtc::point(2.5, 0.0, 0.0));
+
+    assert_eq!(c, white());
+}
+
+#[test]
+fn test_the_default_pattern_transformation() {
+    let pattern = TestPattern::new();
+
+    assert_eq!(*pattern.transform(), trtc::Matrix4::eye());
+}
+
+#[test]
+fn test_assigning_a_transformation() {
+    let mut pattern = TestPattern::new();
+
+    pattern.set_transform(trtc::translation(1.0, 2.0, 3.0));
+
+    assert_eq!(*pattern.transform(), trtc::translation(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_a_pattern_with_an_object_transformation() {
+    let shape = trtc::Sphere::new(trtc::scaling(2.0, 2.0, 2.0));
+    let pattern = TestPattern::new();
+
+    let c = trtc::pattern_at_shape(&pattern, &shape, trtc::point(2.0, 3.0, 4.0));
+
+    assert_eq!(c, trtc::Color::new(1.0, 1.5, 2.0));
+}
+
+#[test]
+fn test_a_pattern_with_a_pattern_transformation() {
+    let shape = trtc::Sphere::default();
+    let mut pattern = TestPattern::new();
+    pattern.set_transform(trtc::scaling(2.0, 2.0, 2.0));
+
+    let c = trtc::pattern_at_shape(&pattern, &shape, trtc::point(2.0, 3.0, 4.0));
+
+    assert_eq!(c, trtc::Color::new(1.0, 1.5, 2.0));
+}
+
+#[test]
+fn test_a_pattern_with_both_an_object_and_a_pattern_transformation() {
+    let shape = trtc::Sphere::new(trtc::scaling(2.0, 2.0, 2.0));
+    let mut pattern = TestPattern::new();
+    pattern.set_transform(trtc::translation(0.5, 1.0, 1.5));
+
+    let c = trtc::pattern_at_shape(&pattern, &shape, trtc::point(2.5, 3.0, 3.5));
+
+    assert_eq!(c, trtc::Color::new(0.75, 0.5, 0.25));
+}
+
+#[test]
+fn test_a_pattern_on_a_child_of_a_transformed_group() {
+    let mut group = trtc::Group::new(trtc::rotation_y(std::f64::consts::PI / 2.0));
+    group.add_child(Box::new(trtc::Sphere::new(trtc::scaling(2.0, 2.0, 2.0))));
+    let shape = group.children()[0].as_ref();
+    let pattern = TestPattern::new();
+
+    let c = trtc::pattern_at_shape(&pattern, shape, trtc::point(2.0, 0.0, 0.0));
+
+    assert_eq!(c, trtc::Color::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_a_gradient_linearly_interpolates_between_colors() {
+    let pattern = trtc::GradientPattern::new(white(), black());
+
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 0.0)), white());
+    assert_eq!(
+        pattern.pattern_at(trtc::point(0.25, 0.0, 0.0)),
+        trtc::Color::new(0.75, 0.75, 0.75)
+    );
+    assert_eq!(
+        pattern.pattern_at(trtc::point(0.5, 0.0, 0.0)),
+        trtc::Color::new(0.5, 0.5, 0.5)
+    );
+    assert_eq!(
+        pattern.pattern_at(trtc::point(0.75, 0.0, 0.0)),
+        trtc::Color::new(0.25, 0.25, 0.25)
+    );
+}
+
+#[test]
+fn test_a_ring_should_extend_in_both_x_and_z() {
+    let pattern = trtc::RingPattern::new(white(), black());
+
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(1.0, 0.0, 0.0)), black());
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 1.0)), black());
+    assert_eq!(pattern.pattern_at(trtc::point(0.708, 0.0, 0.708)), black());
+}
+
+#[test]
+fn test_checkers_should_repeat_in_x() {
+    let pattern = trtc::CheckersPattern::new(white(), black());
+
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(0.99, 0.0, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(1.01, 0.0, 0.0)), black());
+}
+
+#[test]
+fn test_checkers_should_repeat_in_y() {
+    let pattern = trtc::CheckersPattern::new(white(), black());
+
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.99, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 1.01, 0.0)), black());
+}
+
+#[test]
+fn test_checkers_should_repeat_in_z() {
+    let pattern = trtc::CheckersPattern::new(white(), black());
+
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 0.99)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 1.01)), black());
+}
+
+#[test]
+fn test_checkers_alternate_across_negative_coordinates() {
+    let pattern = trtc::CheckersPattern::new(white(), black());
+
+    assert_eq!(pattern.pattern_at(trtc::point(-0.5, 0.0, 0.0)), black());
+    assert_eq!(pattern.pattern_at(trtc::point(-0.5, -0.5, 0.0)), white());
+    assert_eq!(pattern.pattern_at(trtc::point(-0.5, -0.5, -0.5)), black());
+}
+
+#[test]
+fn test_checkers_of_stripes_use_the_nested_pattern_in_each_square() {
+    let red = trtc::Color::new(1.0, 0.0, 0.0);
+    let blue = trtc::Color::new(0.0, 0.0, 1.0);
+    let mut stripes = trtc::StripePattern::new(red, blue);
+    stripes.set_transform(trtc::scaling(0.5, 0.5, 0.5));
+    let pattern = trtc::CheckersPattern::new(stripes, trtc::StripePattern::new(white(), black()));
+
+    assert_eq!(pattern.pattern_at(trtc::point(0.25, 0.0, 0.0)), red);
+    assert_eq!(pattern.pattern_at(trtc::point(0.75, 0.0, 0.0)), blue);
+    assert_eq!(pattern.pattern_at(trtc::point(1.25, 0.0, 0.0)), black());
+    assert_eq!(pattern.pattern_at(trtc::point(0.25, 1.0, 0.0)), white());
+}
+
+#[test]
+fn test_nested_patterns_of_different_kinds() {
+    let pattern = trtc::StripePattern::new(
+        Box::new(TestPattern::new()) as Box<dyn trtc::Pattern>,
+        Box::new(trtc::RingPattern::new(white(), black())),
+    );
+
+    assert_eq!(
+        pattern.pattern_at(trtc::point(0.5, 0.25, 0.75)),
+        trtc::Color::new(0.5, 0.25, 0.75)
+    );
+    assert_eq!(pattern.pattern_at(trtc::point(1.5, 0.0, 0.0)), black());
+}
+
+#[test]
+fn test_a_blend_pattern_averages_two_patterns() {
+    let mut vertical = trtc::StripePattern::new(white(), black());
+    vertical.set_transform(trtc::rotation_y(std::f64::consts::PI / 2.0));
+    let pattern = trtc::BlendPattern::new(trtc::StripePattern::new(white(), black()), vertical);
+
+    assert_eq!(pattern.pattern_at(trtc::point(0.5, 0.0, -0.5)), white());
+    assert_eq!(
+        pattern.pattern_at(trtc::point(1.5, 0.0, -0.5)),
+        trtc::Color::new(0.5, 0.5, 0.5)
+    );
+    assert_eq!(pattern.pattern_at(trtc::point(1.5, 0.0, 0.5)), black());
+}
+
+#[test]
+fn test_a_perturbed_pattern_without_scale_matches_its_input() {
+    let stripes = trtc::StripePattern::new(white(), black());
+    let pattern = trtc::PerturbedPattern::new(stripes, 0.0);
+
+    for x in [0.1, 0.9, 1.1, -0.1, 2.7] {
+        let p = trtc::point(x, 0.3, -0.6);
+
+        assert_eq!(pattern.pattern_at(p), stripes.pattern_at(p));
+    }
+}
+
+#[test]
+fn test_a_perturbed_pattern_jitters_the_lookup_point() {
+    let pattern = trtc::PerturbedPattern::new(TestPattern::new(), 0.5);
+    let p = trtc::point(0.3, 0.6, 0.9);
+
+    let c = pattern.pattern_at(p);
+
+    assert_ne!(c, trtc::Color::new(p.x, p.y, p.z));
+    assert_eq!(c, pattern.pattern_at(p));
+    assert!((c.r - p.x).abs() <= 0.5);
+    assert!((c.g - p.y).abs() <= 0.5);
+    assert!((c.b - p.z).abs() <= 0.5);
+}
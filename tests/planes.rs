@@ -0,0 +1,71 @@
+use trtc::Shape;
+
+#[test]
+fn test_the_normal_of_a_plane_is_constant_everywhere() {
+    let p = trtc::Plane::default();
+
+    let i = trtc::Intersection::new(0.0, &p);
+
+    let n1 = p.local_normal_at(trtc::point(0.0, 0.0, 0.0), &i);
+    let n2 = p.local_normal_at(trtc::point(10.0, 0.0, -10.0), &i);
+    let n3 = p.local_normal_at(trtc::point(-5.0, 0.0, 150.0), &i);
+
+    assert_eq!(n1, trtc::vector(0.0, 1.0, 0.0));
+    assert_eq!(n2, trtc::vector(0.0, 1.0, 0.0));
+    assert_eq!(n3, trtc::vector(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_intersect_with_a_ray_parallel_to_the_plane() {
+    let p = trtc::Plane::default();
+    let r = trtc::Ray::new(trtc::point(0.0, 10.0, 0.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = p.local_intersect(&r);
+
+    assert_eq!(xs.count(), 0);
+}
+
+#[test]
+fn test_intersect_with_a_coplanar_ray() {
+    let p = trtc::Plane::default();
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = p.local_intersect(&r);
+
+    assert_eq!(xs.count(), 0);
+}
+
+#[test]
+fn test_a_ray_intersecting_a_plane_from_above() {
+    let p = trtc::Plane::default();
+    let r = trtc::Ray::new(trtc::point(0.0, 1.0, 0.0), trtc::vector(0.0, -1.0, 0.0));
+
+    let xs = p.local_intersect(&r);
+
+    assert_eq!(xs.count(), 1);
+    assert_eq!(xs[0].t, 1.0);
+    assert!(std::ptr::addr_eq(xs[0].object, &p));
+}
+
+#[test]
+fn test_a_ray_intersecting_a_plane_from_below() {
+    let p = trtc::Plane::default();
+    let r = trtc::Ray::new(trtc::point(0.0, -1.0, 0.0), trtc::vector(0.0, 1.0, 0.0));
+
+    let xs = p.local_intersect(&r);
+
+    assert_eq!(xs.count(), 1);
+    assert_eq!(xs[0].t, 1.0);
+    assert!(std::ptr::addr_eq(xs[0].object, &p));
+}
+
+#[test]
+fn test_intersecting_a_transformed_plane() {
+    let p = trtc::Plane::new(trtc::translation(0.0, -1.0, 0.0));
+    let r = trtc::Ray::new(trtc::point(0.0, 1.0, 0.0), trtc::vector(0.0, -1.0, 0.0));
+
+    let xs = trtc::intersect(&p, &r);
+
+    assert_eq!(xs.count(), 1);
+    assert_eq!(xs[0].t, 2.0);
+}
@@ -0,0 +1,32 @@
+#[test]
+fn test_the_random_generator_is_reproducible_and_in_range() {
+    let mut a = trtc::Rng::new(9);
+    let mut b = trtc::Rng::new(9);
+
+    for _ in 0..1000 {
+        let x = a.next_f64();
+
+        assert_eq!(x, b.next_f64());
+        assert!((0.0..1.0).contains(&x));
+    }
+}
+
+#[test]
+fn test_below_stays_within_bounds() {
+    let mut rng = trtc::Rng::new(5);
+
+    for _ in 0..1000 {
+        assert!(rng.below(7) < 7);
+    }
+}
+
+#[test]
+fn test_seed_hashes_mix_the_seed_and_each_value_separately() {
+    assert_eq!(trtc::seed_hash(3, &[1, 2]), trtc::seed_hash(3, &[1, 2]));
+    assert_ne!(trtc::seed_hash(0, &[1]), trtc::seed_hash(1, &[0]));
+    assert_ne!(trtc::seed_hash(0, &[1, 2]), trtc::seed_hash(0, &[2, 1]));
+    assert_ne!(
+        trtc::seed_hash(0, &[1 << 32]),
+        trtc::seed_hash(1 << 32, &[0])
+    );
+}
@@ -1,5 +1,3 @@
-use trtc;
-
 #[test]
 fn test_creating_and_querying_a_ray() {
     let origin = trtc::point(1.0, 2.0, 3.0);
@@ -86,7 +84,21 @@ fn test_an_intersection_encapsulates_t_and_object() {
     let i = trtc::Intersection::new(3.5, &s);
 
     assert_eq!(i.t, 3.5);
-    assert!(std::ptr::eq(i.object, &s));
+    assert!(std::ptr::addr_eq(i.object, &s));
+}
+
+#[test]
+fn test_an_intersection_can_encapsulate_u_and_v() {
+    let s = trtc::Triangle::new(
+        trtc::point(0.0, 1.0, 0.0),
+        trtc::point(-1.0, 0.0, 0.0),
+        trtc::point(1.0, 0.0, 0.0),
+    );
+
+    let i = trtc::Intersection::with_uv(3.5, &s, 0.2, 0.4);
+
+    assert_eq!(i.u, 0.2);
+    assert_eq!(i.v, 0.4);
 }
 
 #[test]
@@ -113,8 +125,8 @@ fn test_intersect_sets_the_object_on_the_intersection() {
     let xs = trtc::intersect(&s, &r);
 
     assert_eq!(xs.count(), 2);
-    assert!(std::ptr::eq(xs[0].object, &s));
-    assert!(std::ptr::eq(xs[1].object, &s));
+    assert!(std::ptr::addr_eq(xs[0].object, &s));
+    assert!(std::ptr::addr_eq(xs[1].object, &s));
 }
 
 #[test]
@@ -222,9 +234,8 @@ fn test_changing_a_spheres_transformation() {
 #[test]
 fn test_intersecting_a_scaled_sphere_with_a_ray() {
     let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
-    let mut s = trtc::Sphere::default();
+    let s = trtc::Sphere::new(trtc::scaling(2.0, 2.0, 2.0));
 
-    s.transform = trtc::scaling(2.0, 2.0, 2.0);
     let xs = trtc::intersect(&s, &r);
 
     assert_eq!(xs.count(), 2);
@@ -235,10 +246,189 @@ fn test_intersecting_a_scaled_sphere_with_a_ray() {
 #[test]
 fn test_intersecting_a_translated_sphere_with_a_ray() {
     let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
-    let mut s = trtc::Sphere::default();
+    let s = trtc::Sphere::new(trtc::translation(5.0, 0.0, 0.0));
 
-    s.transform = trtc::translation(5.0, 0.0, 0.0);
     let xs = trtc::intersect(&s, &r);
 
     assert_eq!(xs.count(), 0);
 }
+
+#[test]
+fn test_precomputing_the_state_of_an_intersection() {
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let shape = trtc::Sphere::default();
+    let i = trtc::Intersection::new(4.0, &shape);
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+
+    assert_eq!(comps.t, i.t);
+    assert!(std::ptr::addr_eq(comps.object, i.object));
+    assert_eq!(comps.point, trtc::point(0.0, 0.0, -1.0));
+    assert_eq!(comps.eyev, trtc::vector(0.0, 0.0, -1.0));
+    assert_eq!(comps.normalv, trtc::vector(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn test_the_hit_when_an_intersection_occurs_on_the_outside() {
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let shape = trtc::Sphere::default();
+    let i = trtc::Intersection::new(4.0, &shape);
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+
+    assert!(!comps.inside);
+}
+
+#[test]
+fn test_the_hit_when_an_intersection_occurs_on_the_inside() {
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 0.0, 1.0));
+    let shape = trtc::Sphere::default();
+    let i = trtc::Intersection::new(1.0, &shape);
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+
+    assert_eq!(comps.point, trtc::point(0.0, 0.0, 1.0));
+    assert_eq!(comps.eyev, trtc::vector(0.0, 0.0, -1.0));
+    assert!(comps.inside);
+    assert_eq!(comps.normalv, trtc::vector(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn test_the_hit_should_offset_the_point() {
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let shape = trtc::Sphere::new(trtc::translation(0.0, 0.0, 1.0));
+    let i = trtc::Intersection::new(5.0, &shape);
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+
+    assert!(comps.over_point.z < -trtc::EPSILON / 2.0);
+    assert!(comps.point.z > comps.over_point.z);
+}
+
+#[test]
+fn test_precomputing_the_reflection_vector() {
+    let shape = trtc::Plane::default();
+    let k = 2.0_f64.sqrt() / 2.0;
+    let r = trtc::Ray::new(trtc::point(0.0, 1.0, -1.0), trtc::vector(0.0, -k, k));
+    let i = trtc::Intersection::new(2.0_f64.sqrt(), &shape);
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+
+    assert_eq!(comps.reflectv, trtc::vector(0.0, k, k));
+}
+
+fn glass_sphere(transform: trtc::Matrix4, refractive_index: f64) -> trtc::Sphere {
+    let mut s = trtc::Sphere::new(transform);
+    s.material.transparency = 1.0;
+    s.material.refractive_index = refractive_index;
+    s
+}
+
+#[test]
+fn test_finding_n1_and_n2_at_various_intersections() {
+    let a = glass_sphere(trtc::scaling(2.0, 2.0, 2.0), 1.5);
+    let b = glass_sphere(trtc::translation(0.0, 0.0, -0.25), 2.0);
+    let c = glass_sphere(trtc::translation(0.0, 0.0, 0.25), 2.5);
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -4.0), trtc::vector(0.0, 0.0, 1.0));
+    let xs = trtc::Intersections {
+        intersections: vec![
+            trtc::Intersection::new(2.0, &a),
+            trtc::Intersection::new(2.75, &b),
+            trtc::Intersection::new(3.25, &c),
+            trtc::Intersection::new(4.75, &b),
+            trtc::Intersection::new(5.25, &c),
+            trtc::Intersection::new(6.0, &a),
+        ],
+    };
+    let expected = [
+        (1.0, 1.5),
+        (1.5, 2.0),
+        (2.0, 2.5),
+        (2.5, 2.5),
+        (2.5, 1.5),
+        (1.5, 1.0),
+    ];
+
+    for (index, (n1, n2)) in expected.into_iter().enumerate() {
+        let comps = trtc::prepare_computations(&xs[index], &r, &xs);
+
+        assert_eq!(comps.n1, n1);
+        assert_eq!(comps.n2, n2);
+    }
+}
+
+#[test]
+fn test_the_under_point_is_offset_below_the_surface() {
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let shape = glass_sphere(trtc::translation(0.0, 0.0, 1.0), 1.5);
+    let i = trtc::Intersection::new(5.0, &shape);
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+
+    assert!(comps.under_point.z > trtc::EPSILON / 2.0);
+    assert!(comps.point.z < comps.under_point.z);
+}
+
+#[test]
+fn test_the_schlick_approximation_under_total_internal_reflection() {
+    let shape = glass_sphere(trtc::Matrix4::eye(), 1.5);
+    let k = 2.0_f64.sqrt() / 2.0;
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, k), trtc::vector(0.0, 1.0, 0.0));
+    let xs = trtc::Intersections {
+        intersections: vec![
+            trtc::Intersection::new(-k, &shape),
+            trtc::Intersection::new(k, &shape),
+        ],
+    };
+
+    let comps = trtc::prepare_computations(&xs[1], &r, &xs);
+
+    assert_eq!(trtc::schlick(&comps), 1.0);
+}
+
+#[test]
+fn test_the_schlick_approximation_with_a_perpendicular_viewing_angle() {
+    let shape = glass_sphere(trtc::Matrix4::eye(), 1.5);
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 1.0, 0.0));
+    let xs = trtc::Intersections {
+        intersections: vec![
+            trtc::Intersection::new(-1.0, &shape),
+            trtc::Intersection::new(1.0, &shape),
+        ],
+    };
+
+    let comps = trtc::prepare_computations(&xs[1], &r, &xs);
+
+    assert!(trtc::epsilon_eq(trtc::schlick(&comps), 0.04));
+}
+
+#[test]
+fn test_the_schlick_approximation_with_small_angle_and_n2_greater_than_n1() {
+    let shape = glass_sphere(trtc::Matrix4::eye(), 1.5);
+    let r = trtc::Ray::new(trtc::point(0.0, 0.99, -2.0), trtc::vector(0.0, 0.0, 1.0));
+    let xs = trtc::Intersections {
+        intersections: vec![trtc::Intersection::new(1.8589, &shape)],
+    };
+
+    let comps = trtc::prepare_computations(&xs[0], &r, &xs);
+
+    assert!((trtc::schlick(&comps) - 0.48873).abs() < 0.0001);
+}
@@ -0,0 +1,361 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use trtc::Sampler;
+
+fn assert_in_unit_square(samples: &[(f64, f64)]) {
+    for &(x, y) in samples {
+        assert!((0.0..1.0).contains(&x));
+        assert!((0.0..1.0).contains(&y));
+    }
+}
+
+#[test]
+fn test_the_default_sampler_takes_one_sample_at_the_pixel_center() {
+    let sampler = trtc::UniformSampler::default();
+
+    assert_eq!(sampler.samples(3, 7), vec![(0.5, 0.5)]);
+}
+
+#[test]
+fn test_a_uniform_sampler_places_samples_on_a_grid() {
+    let sampler = trtc::UniformSampler::new(4);
+
+    assert_eq!(
+        sampler.samples(0, 0),
+        vec![(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)]
+    );
+}
+
+#[test]
+fn test_sampler_counts_are_total_samples_per_pixel() {
+    let samplers: [&dyn Sampler; 4] = [
+        &trtc::UniformSampler::new(6),
+        &trtc::StratifiedSampler::new(6, 1),
+        &trtc::HaltonSampler::new(6, 1),
+        &trtc::SobolSampler::new(6, 1),
+    ];
+
+    for sampler in samplers {
+        let samples = sampler.samples(2, 5);
+
+        assert_eq!(samples.len(), 6);
+        assert_in_unit_square(&samples);
+    }
+}
+
+#[test]
+fn test_a_uniform_sampler_spreads_a_non_square_count_over_rows() {
+    let sampler = trtc::UniformSampler::new(3);
+
+    assert_eq!(
+        sampler.samples(0, 0),
+        vec![(0.25, 0.25), (0.75, 0.25), (0.5, 0.75)]
+    );
+}
+
+#[test]
+fn test_a_stratified_sampler_jitters_one_sample_per_cell() {
+    let sampler = trtc::StratifiedSampler::new(9, 11);
+
+    let samples = sampler.samples(4, 2);
+
+    assert_eq!(samples.len(), 9);
+    for (k, &(x, y)) in samples.iter().enumerate() {
+        let (i, j) = ((k % 3) as f64, (k / 3) as f64);
+
+        assert!(x >= i / 3.0 && x < (i + 1.0) / 3.0);
+        assert!(y >= j / 3.0 && y < (j + 1.0) / 3.0);
+    }
+}
+
+#[test]
+fn test_a_stratified_sampler_is_reproducible_for_a_seed() {
+    let a = trtc::StratifiedSampler::new(16, 1);
+    let b = trtc::StratifiedSampler::new(16, 1);
+    let c = trtc::StratifiedSampler::new(16, 2);
+
+    assert_eq!(a.samples(10, 20), b.samples(10, 20));
+    assert_ne!(a.samples(10, 20), c.samples(10, 20));
+    assert_ne!(a.samples(10, 20), a.samples(11, 20));
+}
+
+#[test]
+fn test_pixel_seeds_do_not_collide_across_seed_and_position() {
+    let sample = |seed, px, py| trtc::StratifiedSampler::new(1, seed).samples(px, py);
+
+    assert_ne!(sample(0, 1, 0), sample(1, 0, 0));
+    assert_ne!(sample(0, 0, 1), sample(1 << 32, 0, 0));
+    assert_ne!(sample(0, 1, 0), sample(0, 0, 1));
+    assert_ne!(sample(3, 1, 2), sample(3, 2, 1));
+}
+
+#[test]
+fn test_computing_radical_inverses() {
+    assert_eq!(trtc::radical_inverse(2, 1), 0.5);
+    assert_eq!(trtc::radical_inverse(2, 2), 0.25);
+    assert_eq!(trtc::radical_inverse(2, 3), 0.75);
+    assert_eq!(trtc::radical_inverse(2, 6), 0.375);
+    assert!(trtc::epsilon_eq(trtc::radical_inverse(3, 1), 1.0 / 3.0));
+    assert!(trtc::epsilon_eq(trtc::radical_inverse(3, 2), 2.0 / 3.0));
+    assert!(trtc::epsilon_eq(trtc::radical_inverse(3, 3), 1.0 / 9.0));
+}
+
+#[test]
+fn test_a_halton_sampler_produces_reproducible_points() {
+    let sampler = trtc::HaltonSampler::new(16, 5);
+
+    let samples = sampler.samples(1, 2);
+
+    assert_eq!(samples.len(), 16);
+    assert_in_unit_square(&samples);
+    assert_eq!(samples, trtc::HaltonSampler::new(16, 5).samples(1, 2));
+    assert_ne!(samples, trtc::HaltonSampler::new(16, 6).samples(1, 2));
+}
+
+#[test]
+fn test_the_first_points_of_the_sobol_sequence() {
+    assert_eq!(trtc::sobol_2d(0), (0.0, 0.0));
+    assert_eq!(trtc::sobol_2d(1), (0.5, 0.5));
+    assert_eq!(trtc::sobol_2d(2), (0.25, 0.75));
+    assert_eq!(trtc::sobol_2d(3), (0.75, 0.25));
+}
+
+#[test]
+fn test_a_scrambled_sobol_sampler_stays_stratified() {
+    let sampler = trtc::SobolSampler::new(16, 9);
+
+    let samples = sampler.samples(6, 3);
+
+    assert_eq!(samples.len(), 16);
+    assert_in_unit_square(&samples);
+    for stratum in 0..16 {
+        let count = samples
+            .iter()
+            .filter(|(x, _)| (x * 16.0).floor() as usize == stratum)
+            .count();
+
+        assert_eq!(count, 1);
+    }
+    assert_eq!(samples, trtc::SobolSampler::new(16, 9).samples(6, 3));
+    assert_ne!(samples, trtc::SobolSampler::new(16, 10).samples(6, 3));
+}
+
+fn half_covered_pixel_world() -> (trtc::World, trtc::Camera) {
+    let mut cube = trtc::Cube::new(
+        trtc::Matrix4::eye()
+            .scale(1.0, 10.0, 1.0)
+            .translate(1.0, 0.0, -5.0),
+    );
+    cube.material.ambient = 1.0;
+    cube.material.diffuse = 0.0;
+    cube.material.specular = 0.0;
+    let world = trtc::World {
+        objects: vec![Box::new(cube)],
+        lights: vec![Box::new(trtc::PointLight::new(
+            trtc::point(0.0, 0.0, 0.0),
+            trtc::Color::new(1.0, 1.0, 1.0),
+        ))],
+    };
+    let camera = trtc::Camera::new(1, 1, 2.0 * 0.25_f64.atan());
+    (world, camera)
+}
+
+#[test]
+fn test_supersampling_averages_the_samples_of_a_pixel() {
+    let (world, mut camera) = half_covered_pixel_world();
+    let gray = trtc::Color::new(0.5, 0.5, 0.5);
+
+    camera.sampler = Rc::new(trtc::UniformSampler::new(4));
+    assert_eq!(trtc::pixel_color(&camera, &world, 0, 0), gray);
+
+    camera.sampler = Rc::new(trtc::StratifiedSampler::new(16, 3));
+    assert_eq!(trtc::pixel_color(&camera, &world, 0, 0), gray);
+
+    camera.sampler = Rc::new(trtc::SobolSampler::new(16, 3));
+    assert_eq!(trtc::pixel_color(&camera, &world, 0, 0), gray);
+
+    camera.sampler = Rc::new(trtc::HaltonSampler::new(64, 3));
+    let c = trtc::pixel_color(&camera, &world, 0, 0);
+    assert!(c.r > 0.3 && c.r < 0.7);
+}
+
+#[test]
+fn test_rendering_with_a_sampler_is_reproducible() {
+    let (world, mut camera) = half_covered_pixel_world();
+    camera.sampler = Rc::new(trtc::HaltonSampler::new(8, 42));
+
+    let a = trtc::render(&camera, &world);
+    let b = trtc::render(&camera, &world);
+
+    assert_eq!(a.pixels, b.pixels);
+}
+
+fn partially_covered_pixel_world() -> (trtc::World, trtc::Camera) {
+    let mut wall = trtc::Cube::new(
+        trtc::Matrix4::eye()
+            .scale(1.0, 10.0, 0.001)
+            .translate(1.3, 0.0, -4.0),
+    );
+    wall.material.ambient = 1.0;
+    wall.material.diffuse = 0.0;
+    wall.material.specular = 0.0;
+    let world = trtc::World {
+        objects: vec![Box::new(wall)],
+        lights: vec![Box::new(trtc::PointLight::new(
+            trtc::point(0.0, 0.0, 0.0),
+            trtc::Color::new(1.0, 1.0, 1.0),
+        ))],
+    };
+    let camera = trtc::Camera::new(1, 1, 2.0 * 0.25_f64.atan());
+    (world, camera)
+}
+
+#[test]
+fn test_a_camera_does_not_sample_adaptively_by_default() {
+    let c = trtc::Camera::new(160, 120, std::f64::consts::PI / 2.0);
+
+    assert!(c.adaptive.is_none());
+}
+
+#[test]
+fn test_adaptive_sampling_refines_pixels_with_an_edge() {
+    let (world, mut camera) = partially_covered_pixel_world();
+    let single = trtc::pixel_color(&camera, &world, 0, 0);
+    camera.adaptive = Some(trtc::AdaptiveSampling::new(0.1, 4));
+
+    let c = trtc::pixel_color(&camera, &world, 0, 0);
+
+    assert_eq!(single, trtc::Color::new(0.0, 0.0, 0.0));
+    assert!((c.r - 0.35).abs() < 0.03);
+    assert_eq!(c.r, c.g);
+    assert_eq!(c.g, c.b);
+}
+
+#[test]
+fn test_adaptive_sampling_stops_at_the_threshold() {
+    let (world, camera) = partially_covered_pixel_world();
+    let settings = trtc::AdaptiveSampling::new(1.0, 4);
+
+    let c = trtc::adaptive_pixel_color(&camera, &world, 0, 0, &settings);
+
+    assert_eq!(c, trtc::Color::new(0.5, 0.5, 0.5));
+}
+
+#[test]
+fn test_adaptive_sampling_stops_at_the_maximum_depth() {
+    let (world, camera) = partially_covered_pixel_world();
+    let settings = trtc::AdaptiveSampling::new(0.0, 0);
+
+    let c = trtc::adaptive_pixel_color(&camera, &world, 0, 0, &settings);
+
+    assert_eq!(c, trtc::Color::new(0.5, 0.5, 0.5));
+}
+
+#[test]
+fn test_adaptive_sampling_leaves_uniform_pixels_untouched() {
+    let (mut world, camera) = partially_covered_pixel_world();
+    world.objects[0].set_transform(trtc::scaling(10.0, 10.0, 0.001).translate(0.0, 0.0, -4.0));
+    let settings = trtc::AdaptiveSampling::new(0.0, 4);
+
+    let c = trtc::adaptive_pixel_color(&camera, &world, 0, 0, &settings);
+
+    assert_eq!(c, trtc::Color::new(1.0, 1.0, 1.0));
+}
+
+#[derive(Debug)]
+struct RayCounter {
+    transform: trtc::Matrix4,
+    parent_transform: trtc::Matrix4,
+    material: trtc::Material,
+    rays: Rc<Cell<usize>>,
+}
+
+impl trtc::Shape for RayCounter {
+    fn transform(&self) -> &trtc::Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: trtc::Matrix4) {
+        self.transform = transform;
+    }
+
+    fn parent_transform(&self) -> &trtc::Matrix4 {
+        &self.parent_transform
+    }
+
+    fn set_parent_transform(&mut self, transform: trtc::Matrix4) {
+        self.parent_transform = transform;
+    }
+
+    fn material(&self) -> &trtc::Material {
+        &self.material
+    }
+
+    fn material_mut(&mut self) -> &mut trtc::Material {
+        &mut self.material
+    }
+
+    fn bounds(&self) -> trtc::BoundingBox {
+        trtc::BoundingBox::default()
+    }
+
+    fn local_intersect(&self, _ray: &trtc::Ray) -> trtc::Intersections<'_> {
+        self.rays.set(self.rays.get() + 1);
+        trtc::Intersections::new()
+    }
+
+    fn local_normal_at(
+        &self,
+        local_point: trtc::Tuple4,
+        _hit: &trtc::Intersection,
+    ) -> trtc::Tuple4 {
+        trtc::vector(local_point.x, local_point.y, local_point.z)
+    }
+}
+
+#[test]
+fn test_adaptive_rendering_shares_corner_samples_between_pixels() {
+    let rays = Rc::new(Cell::new(0));
+    let world = trtc::World {
+        objects: vec![Box::new(RayCounter {
+            transform: trtc::Matrix4::eye(),
+            parent_transform: trtc::Matrix4::eye(),
+            material: trtc::Material::default(),
+            rays: rays.clone(),
+        })],
+        lights: Vec::new(),
+    };
+    let mut camera = trtc::Camera::new(8, 6, std::f64::consts::PI / 2.0);
+    camera.adaptive = Some(trtc::AdaptiveSampling::new(0.1, 4));
+
+    trtc::render(&camera, &world);
+
+    assert_eq!(rays.get(), 9 * 7);
+}
+
+#[test]
+fn test_adaptive_rendering_matches_sampling_each_pixel_alone() {
+    let (world, mut camera) = partially_covered_pixel_world();
+    camera.hsize = 3;
+    camera.vsize = 2;
+    let settings = trtc::AdaptiveSampling::new(0.1, 3);
+    camera.adaptive = Some(settings);
+
+    let image = trtc::render(&camera, &world);
+
+    for y in 0..2 {
+        for x in 0..3 {
+            assert_eq!(
+                image[(x, y)],
+                trtc::adaptive_pixel_color(&camera, &world, x, y, &settings)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_adaptive_sampling_depth_is_clamped() {
+    let settings = trtc::AdaptiveSampling::new(0.1, 64);
+
+    assert_eq!(settings.max_depth, trtc::MAX_ADAPTIVE_DEPTH);
+}
@@ -0,0 +1,185 @@
+use std::cell::Cell;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+use std::rc::Rc;
+
+#[derive(Debug, Default)]
+struct TestShape {
+    transform: trtc::Matrix4,
+    parent_transform: trtc::Matrix4,
+    material: trtc::Material,
+    saved_ray: Rc<Cell<Option<trtc::Ray>>>,
+}
+
+impl TestShape {
+    fn new() -> Self {
+        Self {
+            transform: trtc::Matrix4::eye(),
+            parent_transform: trtc::Matrix4::eye(),
+            ..Default::default()
+        }
+    }
+}
+
+impl trtc::Shape for TestShape {
+    fn transform(&self) -> &trtc::Matrix4 {
+        &self.transform
+    }
+
+    fn set_transform(&mut self, transform: trtc::Matrix4) {
+        self.transform = transform;
+    }
+
+    fn parent_transform(&self) -> &trtc::Matrix4 {
+        &self.parent_transform
+    }
+
+    fn set_parent_transform(&mut self, transform: trtc::Matrix4) {
+        self.parent_transform = transform;
+    }
+
+    fn material(&self) -> &trtc::Material {
+        &self.material
+    }
+
+    fn material_mut(&mut self) -> &mut trtc::Material {
+        &mut self.material
+    }
+
+    fn bounds(&self) -> trtc::BoundingBox {
+        trtc::BoundingBox::new(trtc::point(-1.0, -1.0, -1.0), trtc::point(1.0, 1.0, 1.0))
+    }
+
+    fn local_intersect(&self, ray: &trtc::Ray) -> trtc::Intersections<'_> {
+        self.saved_ray.set(Some(*ray));
+        trtc::Intersections::new()
+    }
+
+    fn local_normal_at(
+        &self,
+        local_point: trtc::Tuple4,
+        _hit: &trtc::Intersection,
+    ) -> trtc::Tuple4 {
+        trtc::vector(local_point.x, local_point.y, local_point.z)
+    }
+}
+
+#[test]
+fn test_the_default_transformation() {
+    let s = TestShape::new();
+
+    assert_eq!(*trtc::Shape::transform(&s), trtc::Matrix4::eye());
+}
+
+#[test]
+fn test_assigning_a_transformation() {
+    let mut s = TestShape::new();
+
+    trtc::Shape::set_transform(&mut s, trtc::translation(2.0, 3.0, 4.0));
+
+    assert_eq!(
+        *trtc::Shape::transform(&s),
+        trtc::translation(2.0, 3.0, 4.0)
+    );
+}
+
+#[test]
+fn test_the_default_material() {
+    let s = TestShape::new();
+
+    assert_eq!(*trtc::Shape::material(&s), trtc::Material::default());
+}
+
+#[test]
+fn test_assigning_a_material() {
+    let mut s = TestShape::new();
+
+    trtc::Shape::material_mut(&mut s).ambient = 1.0;
+
+    assert_eq!(trtc::Shape::material(&s).ambient, 1.0);
+}
+
+#[test]
+fn test_intersecting_a_scaled_shape_with_a_ray() {
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let mut s = TestShape::new();
+    s.transform = trtc::scaling(2.0, 2.0, 2.0);
+
+    trtc::intersect(&s, &r);
+
+    let saved_ray = s.saved_ray.get().unwrap();
+    assert_eq!(saved_ray.origin, trtc::point(0.0, 0.0, -2.5));
+    assert_eq!(saved_ray.direction, trtc::vector(0.0, 0.0, 0.5));
+}
+
+#[test]
+fn test_intersecting_a_translated_shape_with_a_ray() {
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let mut s = TestShape::new();
+    s.transform = trtc::translation(5.0, 0.0, 0.0);
+
+    trtc::intersect(&s, &r);
+
+    let saved_ray = s.saved_ray.get().unwrap();
+    assert_eq!(saved_ray.origin, trtc::point(-5.0, 0.0, -5.0));
+    assert_eq!(saved_ray.direction, trtc::vector(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_computing_the_normal_on_a_translated_shape() {
+    let mut s = TestShape::new();
+    s.transform = trtc::translation(0.0, 1.0, 0.0);
+
+    let i = trtc::Intersection::new(0.0, &s);
+    let n = trtc::normal_at(
+        &s,
+        trtc::point(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+        &i,
+    );
+
+    assert_eq!(n, trtc::vector(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+}
+
+#[test]
+fn test_computing_the_normal_on_a_transformed_shape() {
+    let mut s = TestShape::new();
+    s.transform = trtc::Matrix4::eye().rotate_z(PI / 5.0).scale(1.0, 0.5, 1.0);
+
+    let k = 2.0_f64.sqrt() / 2.0;
+    let i = trtc::Intersection::new(0.0, &s);
+    let n = trtc::normal_at(&s, trtc::point(0.0, k, -k), &i);
+
+    assert_eq!(n, trtc::vector(0.0, 0.97014, -0.24254));
+}
+
+#[test]
+fn test_a_sphere_is_a_shape() {
+    let s: Box<dyn trtc::Shape> = Box::new(trtc::Sphere::default());
+
+    assert_eq!(*s.transform(), trtc::Matrix4::eye());
+}
+
+#[test]
+fn test_intersecting_ray_and_group_does_not_test_children_if_box_is_missed() {
+    let child = TestShape::new();
+    let saved_ray = child.saved_ray.clone();
+    let mut g = trtc::Group::default();
+    g.add_child(Box::new(child));
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 1.0, 0.0));
+
+    trtc::intersect(&g, &r);
+
+    assert!(saved_ray.get().is_none());
+}
+
+#[test]
+fn test_intersecting_ray_and_group_tests_children_if_box_is_hit() {
+    let child = TestShape::new();
+    let saved_ray = child.saved_ray.clone();
+    let mut g = trtc::Group::default();
+    g.add_child(Box::new(child));
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    trtc::intersect(&g, &r);
+
+    assert!(saved_ray.get().is_some());
+}
@@ -0,0 +1,98 @@
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+#[test]
+fn test_the_normal_on_a_sphere_at_a_point_on_the_x_axis() {
+    let s = trtc::Sphere::default();
+
+    let i = trtc::Intersection::new(0.0, &s);
+    let n = trtc::normal_at(&s, trtc::point(1.0, 0.0, 0.0), &i);
+
+    assert_eq!(n, trtc::vector(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_the_normal_on_a_sphere_at_a_point_on_the_y_axis() {
+    let s = trtc::Sphere::default();
+
+    let i = trtc::Intersection::new(0.0, &s);
+    let n = trtc::normal_at(&s, trtc::point(0.0, 1.0, 0.0), &i);
+
+    assert_eq!(n, trtc::vector(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_the_normal_on_a_sphere_at_a_point_on_the_z_axis() {
+    let s = trtc::Sphere::default();
+
+    let i = trtc::Intersection::new(0.0, &s);
+    let n = trtc::normal_at(&s, trtc::point(0.0, 0.0, 1.0), &i);
+
+    assert_eq!(n, trtc::vector(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_the_normal_on_a_sphere_at_a_nonaxial_point() {
+    let s = trtc::Sphere::default();
+    let k = 3.0_f64.sqrt() / 3.0;
+
+    let i = trtc::Intersection::new(0.0, &s);
+    let n = trtc::normal_at(&s, trtc::point(k, k, k), &i);
+
+    assert_eq!(n, trtc::vector(k, k, k));
+}
+
+#[test]
+fn test_the_normal_is_a_normalized_vector() {
+    let s = trtc::Sphere::default();
+    let k = 3.0_f64.sqrt() / 3.0;
+
+    let i = trtc::Intersection::new(0.0, &s);
+    let n = trtc::normal_at(&s, trtc::point(k, k, k), &i);
+
+    assert_eq!(n, n.normalize());
+}
+
+#[test]
+fn test_computing_the_normal_on_a_translated_sphere() {
+    let s = trtc::Sphere::new(trtc::translation(0.0, 1.0, 0.0));
+
+    let i = trtc::Intersection::new(0.0, &s);
+    let n = trtc::normal_at(
+        &s,
+        trtc::point(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+        &i,
+    );
+
+    assert_eq!(n, trtc::vector(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+}
+
+#[test]
+fn test_computing_the_normal_on_a_transformed_sphere() {
+    let s = trtc::Sphere::new(trtc::Matrix4::eye().rotate_z(PI / 5.0).scale(1.0, 0.5, 1.0));
+
+    let k = 2.0_f64.sqrt() / 2.0;
+    let i = trtc::Intersection::new(0.0, &s);
+    let n = trtc::normal_at(&s, trtc::point(0.0, k, -k), &i);
+
+    assert_eq!(n, trtc::vector(0.0, 0.97014, -0.24254));
+}
+
+#[test]
+fn test_a_sphere_has_a_default_material() {
+    let s = trtc::Sphere::default();
+
+    assert_eq!(s.material, trtc::Material::default());
+}
+
+#[test]
+fn test_a_sphere_may_be_assigned_a_material() {
+    let mut s = trtc::Sphere::default();
+    let m = trtc::Material {
+        ambient: 1.0,
+        ..Default::default()
+    };
+
+    s.material = m.clone();
+
+    assert_eq!(s.material, m);
+}
@@ -0,0 +1,267 @@
+mod common;
+
+use common::{SolidUv, black, white};
+use std::f64::consts::FRAC_1_SQRT_2;
+use trtc::{Pattern, UvPattern};
+
+fn assert_uv_eq(actual: (f64, f64), expected: (f64, f64)) {
+    assert!(
+        trtc::epsilon_eq(actual.0, expected.0) && trtc::epsilon_eq(actual.1, expected.1),
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[derive(Debug)]
+struct TestUv;
+
+impl trtc::UvPattern for TestUv {
+    fn uv_pattern_at(&self, u: f64, v: f64) -> trtc::Color {
+        trtc::Color::new(u, v, 0.0)
+    }
+}
+
+#[test]
+fn test_checker_pattern_in_2d() {
+    let checkers = trtc::UvCheckers::new(2.0, 2.0, black(), white());
+
+    assert_eq!(checkers.uv_pattern_at(0.0, 0.0), black());
+    assert_eq!(checkers.uv_pattern_at(0.5, 0.0), white());
+    assert_eq!(checkers.uv_pattern_at(0.0, 0.5), white());
+    assert_eq!(checkers.uv_pattern_at(0.5, 0.5), black());
+    assert_eq!(checkers.uv_pattern_at(1.0, 1.0), black());
+}
+
+#[test]
+fn test_using_a_spherical_mapping_on_a_3d_point() {
+    let examples = [
+        (trtc::point(0.0, 0.0, -1.0), (0.0, 0.5)),
+        (trtc::point(1.0, 0.0, 0.0), (0.25, 0.5)),
+        (trtc::point(0.0, 0.0, 1.0), (0.5, 0.5)),
+        (trtc::point(-1.0, 0.0, 0.0), (0.75, 0.5)),
+        (trtc::point(0.0, 1.0, 0.0), (0.5, 1.0)),
+        (trtc::point(0.0, -1.0, 0.0), (0.5, 0.0)),
+        (trtc::point(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0), (0.25, 0.75)),
+    ];
+
+    for (p, uv) in examples {
+        assert_uv_eq(trtc::spherical_map(p), uv);
+    }
+}
+
+#[test]
+fn test_using_a_texture_map_pattern_with_a_spherical_map() {
+    let checkers = trtc::UvCheckers::new(16.0, 8.0, black(), white());
+    let pattern = trtc::TextureMapPattern::new(checkers, trtc::UvMapping::Spherical);
+    let examples = [
+        (trtc::point(0.4315, 0.4670, 0.7719), white()),
+        (trtc::point(-0.9654, 0.2552, -0.0534), black()),
+        (trtc::point(0.1039, 0.7090, 0.6975), white()),
+        (trtc::point(-0.4986, -0.7856, -0.3663), black()),
+        (trtc::point(-0.0317, -0.9395, 0.3411), black()),
+        (trtc::point(0.4809, -0.7721, 0.4154), black()),
+        (trtc::point(0.0285, -0.9612, -0.2745), black()),
+        (trtc::point(-0.5734, -0.2162, -0.7903), white()),
+        (trtc::point(0.7688, -0.1470, 0.6223), black()),
+        (trtc::point(-0.7652, 0.2175, 0.6060), black()),
+    ];
+
+    for (p, color) in examples {
+        assert_eq!(pattern.pattern_at(p), color);
+    }
+}
+
+#[test]
+fn test_using_a_planar_mapping_on_a_3d_point() {
+    let examples = [
+        (trtc::point(0.25, 0.0, 0.5), (0.25, 0.5)),
+        (trtc::point(0.25, 0.0, -0.25), (0.25, 0.75)),
+        (trtc::point(0.25, 0.5, -0.25), (0.25, 0.75)),
+        (trtc::point(1.25, 0.0, 0.5), (0.25, 0.5)),
+        (trtc::point(0.25, 0.0, -1.75), (0.25, 0.25)),
+        (trtc::point(1.0, 0.0, -1.0), (0.0, 0.0)),
+        (trtc::point(0.0, 0.0, 0.0), (0.0, 0.0)),
+    ];
+
+    for (p, uv) in examples {
+        assert_uv_eq(trtc::planar_map(p), uv);
+    }
+}
+
+#[test]
+fn test_using_a_cylindrical_mapping_on_a_3d_point() {
+    let examples = [
+        (trtc::point(0.0, 0.0, -1.0), (0.0, 0.0)),
+        (trtc::point(0.0, 0.5, -1.0), (0.0, 0.5)),
+        (trtc::point(0.0, 1.0, -1.0), (0.0, 0.0)),
+        (
+            trtc::point(FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2),
+            (0.125, 0.5),
+        ),
+        (trtc::point(1.0, 0.5, 0.0), (0.25, 0.5)),
+        (trtc::point(FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), (0.375, 0.5)),
+        (trtc::point(0.0, -0.25, 1.0), (0.5, 0.75)),
+        (
+            trtc::point(-FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2),
+            (0.625, 0.5),
+        ),
+        (trtc::point(-1.0, 1.25, 0.0), (0.75, 0.25)),
+        (
+            trtc::point(-FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2),
+            (0.875, 0.5),
+        ),
+    ];
+
+    for (p, uv) in examples {
+        assert_uv_eq(trtc::cylindrical_map(p), uv);
+    }
+}
+
+#[test]
+fn test_identifying_the_face_of_a_cube_from_a_point() {
+    use trtc::CubeFace::*;
+    let examples = [
+        (trtc::point(-1.0, 0.5, -0.25), Left),
+        (trtc::point(1.1, -0.75, 0.8), Right),
+        (trtc::point(0.1, 0.6, 0.9), Front),
+        (trtc::point(-0.7, 0.0, -2.0), Back),
+        (trtc::point(0.5, 1.0, 0.9), Up),
+        (trtc::point(-0.2, -1.3, 1.1), Down),
+    ];
+
+    for (p, face) in examples {
+        assert_eq!(trtc::face_from_point(p), face);
+    }
+}
+
+#[test]
+fn test_uv_mapping_each_face_of_a_cube() {
+    use trtc::CubeFace::*;
+    #[rustfmt::skip]
+    let examples = [
+        (Front, trtc::point(-0.5,  0.5,  1.0), (0.25, 0.75)),
+        (Front, trtc::point( 0.5, -0.5,  1.0), (0.75, 0.25)),
+        (Back,  trtc::point( 0.5,  0.5, -1.0), (0.25, 0.75)),
+        (Back,  trtc::point(-0.5, -0.5, -1.0), (0.75, 0.25)),
+        (Left,  trtc::point(-1.0,  0.5, -0.5), (0.25, 0.75)),
+        (Left,  trtc::point(-1.0, -0.5,  0.5), (0.75, 0.25)),
+        (Right, trtc::point( 1.0,  0.5,  0.5), (0.25, 0.75)),
+        (Right, trtc::point( 1.0, -0.5, -0.5), (0.75, 0.25)),
+        (Up,    trtc::point(-0.5,  1.0, -0.5), (0.25, 0.75)),
+        (Up,    trtc::point( 0.5,  1.0,  0.5), (0.75, 0.25)),
+        (Down,  trtc::point(-0.5, -1.0,  0.5), (0.25, 0.75)),
+        (Down,  trtc::point( 0.5, -1.0, -0.5), (0.75, 0.25)),
+    ];
+
+    for (face, p, uv) in examples {
+        assert_uv_eq(trtc::cube_uv(face, p), uv);
+    }
+}
+
+#[test]
+fn test_a_cube_map_selects_the_pattern_for_each_face() {
+    let red = trtc::Color::new(1.0, 0.0, 0.0);
+    let yellow = trtc::Color::new(1.0, 1.0, 0.0);
+    let green = trtc::Color::new(0.0, 1.0, 0.0);
+    let cyan = trtc::Color::new(0.0, 1.0, 1.0);
+    let blue = trtc::Color::new(0.0, 0.0, 1.0);
+    let pattern = trtc::CubeMapPattern::new(
+        Box::new(SolidUv(red)) as Box<dyn trtc::UvPattern>,
+        Box::new(SolidUv(yellow)),
+        Box::new(SolidUv(green)),
+        Box::new(SolidUv(cyan)),
+        Box::new(SolidUv(blue)),
+        Box::new(TestUv),
+    );
+
+    assert_eq!(pattern.pattern_at(trtc::point(-1.0, 0.0, 0.0)), red);
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, 1.0)), yellow);
+    assert_eq!(pattern.pattern_at(trtc::point(1.0, 0.0, 0.0)), green);
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 0.0, -1.0)), cyan);
+    assert_eq!(pattern.pattern_at(trtc::point(0.0, 1.0, 0.0)), blue);
+    assert_eq!(
+        pattern.pattern_at(trtc::point(-0.5, -1.0, 0.5)),
+        trtc::Color::new(0.25, 0.75, 0.0)
+    );
+}
+
+fn two_by_two_image() -> trtc::ImageTexture {
+    let mut canvas = trtc::Canvas::new(2, 2);
+    canvas[(0, 0)] = trtc::Color::new(1.0, 0.0, 0.0);
+    canvas[(1, 0)] = trtc::Color::new(0.0, 1.0, 0.0);
+    canvas[(0, 1)] = trtc::Color::new(0.0, 0.0, 1.0);
+    canvas[(1, 1)] = trtc::Color::new(1.0, 1.0, 1.0);
+    trtc::ImageTexture::new(canvas)
+}
+
+#[test]
+fn test_an_image_texture_maps_corners_to_pixels() {
+    let image = two_by_two_image();
+
+    assert_eq!(
+        image.uv_pattern_at(0.0, 1.0),
+        trtc::Color::new(1.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        image.uv_pattern_at(1.0, 1.0),
+        trtc::Color::new(0.0, 1.0, 0.0)
+    );
+    assert_eq!(
+        image.uv_pattern_at(0.0, 0.0),
+        trtc::Color::new(0.0, 0.0, 1.0)
+    );
+    assert_eq!(
+        image.uv_pattern_at(1.0, 0.0),
+        trtc::Color::new(1.0, 1.0, 1.0)
+    );
+}
+
+#[test]
+fn test_an_image_texture_filters_bilinearly_between_pixels() {
+    let image = two_by_two_image();
+
+    assert_eq!(
+        image.uv_pattern_at(0.5, 1.0),
+        trtc::Color::new(0.5, 0.5, 0.0)
+    );
+    assert_eq!(
+        image.uv_pattern_at(0.0, 0.5),
+        trtc::Color::new(0.5, 0.0, 0.5)
+    );
+    assert_eq!(
+        image.uv_pattern_at(0.5, 0.5),
+        trtc::Color::new(0.5, 0.5, 0.5)
+    );
+    assert_eq!(
+        image.uv_pattern_at(0.25, 0.75),
+        trtc::Color::new(0.625, 0.25, 0.25)
+    );
+}
+
+#[test]
+fn test_an_empty_image_texture_is_black() {
+    let black = trtc::Color::new(0.0, 0.0, 0.0);
+
+    for canvas in [
+        trtc::Canvas::new(0, 0),
+        trtc::Canvas::new(4, 0),
+        trtc::Canvas::new(0, 4),
+    ] {
+        let texture = trtc::ImageTexture::new(canvas);
+
+        assert_eq!(texture.uv_pattern_at(0.5, 0.5), black);
+    }
+}
+
+#[test]
+fn test_an_image_texture_wrapped_onto_a_sphere() {
+    let pattern = trtc::TextureMapPattern::new(two_by_two_image(), trtc::UvMapping::Spherical);
+    let mut sphere = trtc::Sphere::new(trtc::scaling(2.0, 2.0, 2.0));
+    sphere.material.pattern = Some(std::rc::Rc::new(pattern));
+    let p = sphere.material.pattern.as_ref().unwrap();
+
+    let north = trtc::pattern_at_shape(p.as_ref(), &sphere, trtc::point(0.0, 2.0, 0.0));
+    let south = trtc::pattern_at_shape(p.as_ref(), &sphere, trtc::point(0.0, -2.0, 0.0));
+
+    assert_eq!(north, trtc::Color::new(0.5, 0.5, 0.0));
+    assert_eq!(south, trtc::Color::new(0.5, 0.5, 1.0));
+}
@@ -1,5 +1,3 @@
-use trtc;
-
 #[test]
 fn test_multiplying_by_a_translation_matrix() {
     let transform = trtc::translation(5.0, -3.0, 2.0);
@@ -195,3 +193,54 @@ fn test_fluent_transformations_are_applied_in_correct_order() {
 
     assert_eq!(t * p, trtc::point(15.0, 0.0, 7.0));
 }
+
+#[test]
+fn test_the_transformation_matrix_for_the_default_orientation() {
+    let from = trtc::point(0.0, 0.0, 0.0);
+    let to = trtc::point(0.0, 0.0, -1.0);
+    let up = trtc::vector(0.0, 1.0, 0.0);
+
+    let t = trtc::view_transform(from, to, up);
+
+    assert_eq!(t, trtc::Matrix4::eye());
+}
+
+#[test]
+fn test_a_view_transformation_matrix_looking_in_positive_z_direction() {
+    let from = trtc::point(0.0, 0.0, 0.0);
+    let to = trtc::point(0.0, 0.0, 1.0);
+    let up = trtc::vector(0.0, 1.0, 0.0);
+
+    let t = trtc::view_transform(from, to, up);
+
+    assert_eq!(t, trtc::scaling(-1.0, 1.0, -1.0));
+}
+
+#[test]
+fn test_the_view_transformation_moves_the_world() {
+    let from = trtc::point(0.0, 0.0, 8.0);
+    let to = trtc::point(0.0, 0.0, 0.0);
+    let up = trtc::vector(0.0, 1.0, 0.0);
+
+    let t = trtc::view_transform(from, to, up);
+
+    assert_eq!(t, trtc::translation(0.0, 0.0, -8.0));
+}
+
+#[test]
+fn test_an_arbitrary_view_transformation() {
+    let from = trtc::point(1.0, 3.0, 2.0);
+    let to = trtc::point(4.0, -2.0, 8.0);
+    let up = trtc::vector(1.0, 1.0, 0.0);
+
+    let t = trtc::view_transform(from, to, up);
+
+    #[rustfmt::skip]
+    let expected = trtc::Matrix4::from_array([
+        [-0.50709, 0.50709,  0.67612, -2.36643],
+        [ 0.76772, 0.60609,  0.12122, -2.82843],
+        [-0.35857, 0.59761, -0.71714,  0.00000],
+        [ 0.00000, 0.00000,  0.00000,  1.00000],
+    ]);
+    assert_eq!(t, expected);
+}
@@ -0,0 +1,144 @@
+use trtc::Shape;
+
+fn smooth_triangle() -> trtc::SmoothTriangle {
+    trtc::SmoothTriangle::new(
+        trtc::point(0.0, 1.0, 0.0),
+        trtc::point(-1.0, 0.0, 0.0),
+        trtc::point(1.0, 0.0, 0.0),
+        trtc::vector(0.0, 1.0, 0.0),
+        trtc::vector(-1.0, 0.0, 0.0),
+        trtc::vector(1.0, 0.0, 0.0),
+    )
+}
+
+#[test]
+fn test_constructing_a_triangle() {
+    let p1 = trtc::point(0.0, 1.0, 0.0);
+    let p2 = trtc::point(-1.0, 0.0, 0.0);
+    let p3 = trtc::point(1.0, 0.0, 0.0);
+
+    let t = trtc::Triangle::new(p1, p2, p3);
+
+    assert_eq!(t.p1, p1);
+    assert_eq!(t.p2, p2);
+    assert_eq!(t.p3, p3);
+    assert_eq!(t.e1, trtc::vector(-1.0, -1.0, 0.0));
+    assert_eq!(t.e2, trtc::vector(1.0, -1.0, 0.0));
+    assert_eq!(t.normal, trtc::vector(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn test_finding_the_normal_on_a_triangle() {
+    let t = trtc::Triangle::new(
+        trtc::point(0.0, 1.0, 0.0),
+        trtc::point(-1.0, 0.0, 0.0),
+        trtc::point(1.0, 0.0, 0.0),
+    );
+    let i = trtc::Intersection::new(0.0, &t);
+
+    let n1 = t.local_normal_at(trtc::point(0.0, 0.5, 0.0), &i);
+    let n2 = t.local_normal_at(trtc::point(-0.5, 0.75, 0.0), &i);
+    let n3 = t.local_normal_at(trtc::point(0.5, 0.25, 0.0), &i);
+
+    assert_eq!(n1, t.normal);
+    assert_eq!(n2, t.normal);
+    assert_eq!(n3, t.normal);
+}
+
+#[test]
+fn test_intersecting_a_ray_parallel_to_the_triangle() {
+    let t = trtc::Triangle::new(
+        trtc::point(0.0, 1.0, 0.0),
+        trtc::point(-1.0, 0.0, 0.0),
+        trtc::point(1.0, 0.0, 0.0),
+    );
+    let r = trtc::Ray::new(trtc::point(0.0, -1.0, -2.0), trtc::vector(0.0, 1.0, 0.0));
+
+    let xs = t.local_intersect(&r);
+
+    assert_eq!(xs.count(), 0);
+}
+
+#[test]
+fn test_a_ray_misses_the_edges_of_a_triangle() {
+    let t = trtc::Triangle::new(
+        trtc::point(0.0, 1.0, 0.0),
+        trtc::point(-1.0, 0.0, 0.0),
+        trtc::point(1.0, 0.0, 0.0),
+    );
+    let origins = [
+        trtc::point(1.0, 1.0, -2.0),
+        trtc::point(-1.0, 1.0, -2.0),
+        trtc::point(0.0, -1.0, -2.0),
+    ];
+
+    for origin in origins {
+        let r = trtc::Ray::new(origin, trtc::vector(0.0, 0.0, 1.0));
+
+        let xs = t.local_intersect(&r);
+
+        assert_eq!(xs.count(), 0);
+    }
+}
+
+#[test]
+fn test_a_ray_strikes_a_triangle() {
+    let t = trtc::Triangle::new(
+        trtc::point(0.0, 1.0, 0.0),
+        trtc::point(-1.0, 0.0, 0.0),
+        trtc::point(1.0, 0.0, 0.0),
+    );
+    let r = trtc::Ray::new(trtc::point(0.0, 0.5, -2.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = t.local_intersect(&r);
+
+    assert_eq!(xs.count(), 1);
+    assert_eq!(xs[0].t, 2.0);
+}
+
+#[test]
+fn test_constructing_a_smooth_triangle() {
+    let tri = smooth_triangle();
+
+    assert_eq!(tri.p1, trtc::point(0.0, 1.0, 0.0));
+    assert_eq!(tri.p2, trtc::point(-1.0, 0.0, 0.0));
+    assert_eq!(tri.p3, trtc::point(1.0, 0.0, 0.0));
+    assert_eq!(tri.n1, trtc::vector(0.0, 1.0, 0.0));
+    assert_eq!(tri.n2, trtc::vector(-1.0, 0.0, 0.0));
+    assert_eq!(tri.n3, trtc::vector(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_an_intersection_with_a_smooth_triangle_stores_u_v() {
+    let tri = smooth_triangle();
+    let r = trtc::Ray::new(trtc::point(-0.2, 0.3, -2.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = tri.local_intersect(&r);
+
+    assert!((xs[0].u - 0.45).abs() < 0.00001);
+    assert!((xs[0].v - 0.25).abs() < 0.00001);
+}
+
+#[test]
+fn test_a_smooth_triangle_uses_u_v_to_interpolate_the_normal() {
+    let tri = smooth_triangle();
+    let i = trtc::Intersection::with_uv(1.0, &tri, 0.45, 0.25);
+
+    let n = trtc::normal_at(&tri, trtc::point(0.0, 0.0, 0.0), &i);
+
+    assert_eq!(n, trtc::vector(-0.5547, 0.83205, 0.0));
+}
+
+#[test]
+fn test_preparing_the_normal_on_a_smooth_triangle() {
+    let tri = smooth_triangle();
+    let i = trtc::Intersection::with_uv(1.0, &tri, 0.45, 0.25);
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+    let r = trtc::Ray::new(trtc::point(-0.2, 0.3, -2.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+
+    assert_eq!(comps.normalv, trtc::vector(-0.5547, 0.83205, 0.0));
+}
@@ -1,5 +1,3 @@
-use trtc;
-
 #[test]
 fn test_a_tuple_with_w_eq_1_is_a_point() {
     let a = trtc::Tuple4::from_array([4.3, -4.2, 3.1, 1.0]);
@@ -188,6 +186,22 @@ fn test_the_cross_product_of_two_vectors() {
     assert_eq!(b.cross(&a), trtc::vector(1.0, -2.0, 1.0));
 }
 
+#[test]
+fn test_reflecting_a_vector_approaching_at_45_degrees() {
+    let v = trtc::vector(1.0, -1.0, 0.0);
+    let n = trtc::vector(0.0, 1.0, 0.0);
+
+    assert_eq!(v.reflect(&n), trtc::vector(1.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_reflecting_a_vector_off_a_slanted_surface() {
+    let v = trtc::vector(0.0, -1.0, 0.0);
+    let n = trtc::vector(2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0, 0.0);
+
+    assert_eq!(v.reflect(&n), trtc::vector(1.0, 0.0, 0.0));
+}
+
 #[test]
 fn test_colors_are_red_green_blue_tuples() {
     let c = trtc::Color::new(-0.5, 0.4, 1.7);
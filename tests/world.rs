@@ -0,0 +1,693 @@
+mod common;
+
+use common::{TestPattern, default_world};
+
+#[test]
+fn test_creating_a_world() {
+    let w = trtc::World::new();
+
+    assert!(w.objects.is_empty());
+    assert!(w.lights.is_empty());
+}
+
+#[test]
+fn test_intersect_a_world_with_a_ray() {
+    let w = default_world();
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = trtc::intersect_world(&w, &r);
+
+    assert_eq!(xs.count(), 4);
+    assert_eq!(xs[0].t, 4.0);
+    assert_eq!(xs[1].t, 4.5);
+    assert_eq!(xs[2].t, 5.5);
+    assert_eq!(xs[3].t, 6.0);
+}
+
+#[test]
+fn test_dividing_a_world_groups_its_bounded_objects() {
+    let mut w = default_world();
+    w.objects.push(Box::new(trtc::Plane::new(trtc::translation(
+        0.0, -1.0, 0.0,
+    ))));
+    w.objects.push(Box::new(trtc::Sphere::new(trtc::translation(
+        5.0, 0.0, 0.0,
+    ))));
+
+    w.divide(1);
+
+    assert_eq!(w.objects.len(), 2);
+    assert!(!trtc::parent_space_bounds_of(w.objects[0].as_ref()).is_finite());
+    assert!(trtc::parent_space_bounds_of(w.objects[1].as_ref()).is_finite());
+}
+
+#[test]
+fn test_dividing_a_world_preserves_its_intersections() {
+    let mut w = default_world();
+    w.objects.push(Box::new(trtc::Sphere::new(trtc::translation(
+        5.0, 0.0, 0.0,
+    ))));
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    w.divide(1);
+    let xs = trtc::intersect_world(&w, &r);
+
+    assert_eq!(xs.count(), 4);
+    assert_eq!(xs[0].t, 4.0);
+    assert_eq!(xs[1].t, 4.5);
+    assert_eq!(xs[2].t, 5.5);
+    assert_eq!(xs[3].t, 6.0);
+}
+
+#[test]
+fn test_shading_an_intersection() {
+    let w = default_world();
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let shape = w.objects[0].as_ref();
+    let i = trtc::Intersection::new(4.0, shape);
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+    let c = trtc::shade_hit(&w, &comps, 5);
+
+    assert_eq!(c, trtc::Color::new(0.38066, 0.47583, 0.2855));
+}
+
+#[test]
+fn test_shading_an_intersection_from_the_inside() {
+    let mut w = default_world();
+    w.lights = vec![Box::new(trtc::PointLight::new(
+        trtc::point(0.0, 0.25, 0.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    ))];
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 0.0, 1.0));
+    let shape = w.objects[1].as_ref();
+    let i = trtc::Intersection::new(0.5, shape);
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+    let c = trtc::shade_hit(&w, &comps, 5);
+
+    assert_eq!(c, trtc::Color::new(0.90498, 0.90498, 0.90498));
+}
+
+#[test]
+fn test_the_color_when_a_ray_misses() {
+    let w = default_world();
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 1.0, 0.0));
+
+    let c = trtc::color_at(&w, &r, 5);
+
+    assert_eq!(c, trtc::Color::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_the_color_when_a_ray_hits() {
+    let w = default_world();
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let c = trtc::color_at(&w, &r, 5);
+
+    assert_eq!(c, trtc::Color::new(0.38066, 0.47583, 0.2855));
+}
+
+#[test]
+fn test_the_color_with_an_intersection_behind_the_ray() {
+    let mut w = default_world();
+    w.objects[0].material_mut().ambient = 1.0;
+    w.objects[1].material_mut().ambient = 1.0;
+    let inner_color = w.objects[1].material().color;
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.75), trtc::vector(0.0, 0.0, -1.0));
+
+    let c = trtc::color_at(&w, &r, 5);
+
+    assert_eq!(c, inner_color);
+}
+
+#[test]
+fn test_there_is_no_shadow_when_nothing_is_collinear_with_point_and_light() {
+    let w = default_world();
+    let p = trtc::point(0.0, 10.0, 0.0);
+
+    assert!(!trtc::is_shadowed(&w, p, trtc::point(-10.0, 10.0, -10.0)));
+}
+
+#[test]
+fn test_the_shadow_when_an_object_is_between_the_point_and_the_light() {
+    let w = default_world();
+    let p = trtc::point(10.0, -10.0, 10.0);
+
+    assert!(trtc::is_shadowed(&w, p, trtc::point(-10.0, 10.0, -10.0)));
+}
+
+#[test]
+fn test_there_is_no_shadow_when_an_object_is_behind_the_light() {
+    let w = default_world();
+    let p = trtc::point(-20.0, 20.0, -20.0);
+
+    assert!(!trtc::is_shadowed(&w, p, trtc::point(-10.0, 10.0, -10.0)));
+}
+
+#[test]
+fn test_there_is_no_shadow_when_an_object_is_behind_the_point() {
+    let w = default_world();
+    let p = trtc::point(-2.0, 2.0, -2.0);
+
+    assert!(!trtc::is_shadowed(&w, p, trtc::point(-10.0, 10.0, -10.0)));
+}
+
+#[test]
+fn test_shade_hit_is_given_an_intersection_in_shadow() {
+    let w = trtc::World {
+        objects: vec![
+            Box::new(trtc::Sphere::default()),
+            Box::new(trtc::Sphere::new(trtc::translation(0.0, 0.0, 10.0))),
+        ],
+        lights: vec![Box::new(trtc::PointLight::new(
+            trtc::point(0.0, 0.0, -10.0),
+            trtc::Color::new(1.0, 1.0, 1.0),
+        ))],
+    };
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 5.0), trtc::vector(0.0, 0.0, 1.0));
+    let i = trtc::Intersection::new(4.0, w.objects[1].as_ref());
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+    let c = trtc::shade_hit(&w, &comps, 5);
+
+    assert_eq!(c, trtc::Color::new(0.1, 0.1, 0.1));
+}
+
+fn reflective_plane() -> trtc::Plane {
+    let mut shape = trtc::Plane::new(trtc::translation(0.0, -1.0, 0.0));
+    shape.material.reflective = 0.5;
+    shape
+}
+
+#[test]
+fn test_the_reflected_color_for_a_nonreflective_material() {
+    let mut w = default_world();
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 0.0, 1.0));
+    w.objects[1].material_mut().ambient = 1.0;
+    let shape = w.objects[1].as_ref();
+    let i = trtc::Intersection::new(1.0, shape);
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+    let color = trtc::reflected_color(&w, &comps, 5);
+
+    assert_eq!(color, trtc::Color::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_the_reflected_color_for_a_reflective_material() {
+    let mut w = default_world();
+    w.objects.push(Box::new(reflective_plane()));
+    let k = 2.0_f64.sqrt() / 2.0;
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -3.0), trtc::vector(0.0, -k, k));
+    let i = trtc::Intersection::new(2.0_f64.sqrt(), w.objects[2].as_ref());
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+    let color = trtc::reflected_color(&w, &comps, 5);
+
+    assert!((color - trtc::Color::new(0.19032, 0.2379, 0.14274)).r.abs() < 0.0001);
+    assert!((color - trtc::Color::new(0.19032, 0.2379, 0.14274)).g.abs() < 0.0001);
+    assert!((color - trtc::Color::new(0.19032, 0.2379, 0.14274)).b.abs() < 0.0001);
+}
+
+#[test]
+fn test_shade_hit_with_a_reflective_material() {
+    let mut w = default_world();
+    w.objects.push(Box::new(reflective_plane()));
+    let k = 2.0_f64.sqrt() / 2.0;
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -3.0), trtc::vector(0.0, -k, k));
+    let i = trtc::Intersection::new(2.0_f64.sqrt(), w.objects[2].as_ref());
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+    let color = trtc::shade_hit(&w, &comps, 5);
+
+    assert!(
+        (color - trtc::Color::new(0.87677, 0.92436, 0.82918))
+            .r
+            .abs()
+            < 0.0001
+    );
+    assert!(
+        (color - trtc::Color::new(0.87677, 0.92436, 0.82918))
+            .g
+            .abs()
+            < 0.0001
+    );
+    assert!(
+        (color - trtc::Color::new(0.87677, 0.92436, 0.82918))
+            .b
+            .abs()
+            < 0.0001
+    );
+}
+
+#[test]
+fn test_color_at_with_mutually_reflective_surfaces() {
+    let mut lower = trtc::Plane::new(trtc::translation(0.0, -1.0, 0.0));
+    lower.material.reflective = 1.0;
+    let mut upper = trtc::Plane::new(trtc::translation(0.0, 1.0, 0.0));
+    upper.material.reflective = 1.0;
+    let w = trtc::World {
+        objects: vec![Box::new(lower), Box::new(upper)],
+        lights: vec![Box::new(trtc::PointLight::new(
+            trtc::point(0.0, 0.0, 0.0),
+            trtc::Color::new(1.0, 1.0, 1.0),
+        ))],
+    };
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.0), trtc::vector(0.0, 1.0, 0.0));
+
+    let color = trtc::color_at(&w, &r, 5);
+
+    assert!(color.r > 0.0);
+}
+
+#[test]
+fn test_the_reflected_color_at_the_maximum_recursive_depth() {
+    let mut w = default_world();
+    w.objects.push(Box::new(reflective_plane()));
+    let k = 2.0_f64.sqrt() / 2.0;
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -3.0), trtc::vector(0.0, -k, k));
+    let i = trtc::Intersection::new(2.0_f64.sqrt(), w.objects[2].as_ref());
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+    let color = trtc::reflected_color(&w, &comps, 0);
+
+    assert_eq!(color, trtc::Color::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_the_refracted_color_with_an_opaque_surface() {
+    let w = default_world();
+    let shape = w.objects[0].as_ref();
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let xs = trtc::Intersections {
+        intersections: vec![
+            trtc::Intersection::new(4.0, shape),
+            trtc::Intersection::new(6.0, shape),
+        ],
+    };
+
+    let comps = trtc::prepare_computations(&xs[0], &r, &xs);
+    let c = trtc::refracted_color(&w, &comps, 5);
+
+    assert_eq!(c, trtc::Color::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_the_refracted_color_at_the_maximum_recursive_depth() {
+    let mut w = default_world();
+    w.objects[0].material_mut().transparency = 1.0;
+    w.objects[0].material_mut().refractive_index = 1.5;
+    let shape = w.objects[0].as_ref();
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let xs = trtc::Intersections {
+        intersections: vec![
+            trtc::Intersection::new(4.0, shape),
+            trtc::Intersection::new(6.0, shape),
+        ],
+    };
+
+    let comps = trtc::prepare_computations(&xs[0], &r, &xs);
+    let c = trtc::refracted_color(&w, &comps, 0);
+
+    assert_eq!(c, trtc::Color::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_the_refracted_color_under_total_internal_reflection() {
+    let mut w = default_world();
+    w.objects[0].material_mut().transparency = 1.0;
+    w.objects[0].material_mut().refractive_index = 1.5;
+    let shape = w.objects[0].as_ref();
+    let k = 2.0_f64.sqrt() / 2.0;
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, k), trtc::vector(0.0, 1.0, 0.0));
+    let xs = trtc::Intersections {
+        intersections: vec![
+            trtc::Intersection::new(-k, shape),
+            trtc::Intersection::new(k, shape),
+        ],
+    };
+
+    let comps = trtc::prepare_computations(&xs[1], &r, &xs);
+    let c = trtc::refracted_color(&w, &comps, 5);
+
+    assert_eq!(c, trtc::Color::new(0.0, 0.0, 0.0));
+}
+
+fn transparent_floor(reflective: f64) -> trtc::Plane {
+    let mut floor = trtc::Plane::new(trtc::translation(0.0, -1.0, 0.0));
+    floor.material.reflective = reflective;
+    floor.material.transparency = 0.5;
+    floor.material.refractive_index = 1.5;
+    floor
+}
+
+fn red_ball() -> trtc::Sphere {
+    let mut ball = trtc::Sphere::new(trtc::translation(0.0, -3.5, -0.5));
+    ball.material.color = trtc::Color::new(1.0, 0.0, 0.0);
+    ball.material.ambient = 0.5;
+    ball
+}
+
+#[test]
+fn test_shade_hit_with_a_transparent_material() {
+    let mut w = default_world();
+    w.objects.push(Box::new(transparent_floor(0.0)));
+    w.objects.push(Box::new(red_ball()));
+    let k = 2.0_f64.sqrt() / 2.0;
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -3.0), trtc::vector(0.0, -k, k));
+    let xs = trtc::Intersections {
+        intersections: vec![trtc::Intersection::new(
+            2.0_f64.sqrt(),
+            w.objects[2].as_ref(),
+        )],
+    };
+
+    let comps = trtc::prepare_computations(&xs[0], &r, &xs);
+    let color = trtc::shade_hit(&w, &comps, 5);
+
+    assert!(
+        (color - trtc::Color::new(0.93642, 0.68642, 0.68642))
+            .r
+            .abs()
+            < 0.0001
+    );
+    assert!(
+        (color - trtc::Color::new(0.93642, 0.68642, 0.68642))
+            .g
+            .abs()
+            < 0.0001
+    );
+    assert!(
+        (color - trtc::Color::new(0.93642, 0.68642, 0.68642))
+            .b
+            .abs()
+            < 0.0001
+    );
+}
+
+#[test]
+fn test_shade_hit_with_a_reflective_transparent_material() {
+    let mut w = default_world();
+    w.objects.push(Box::new(transparent_floor(0.5)));
+    w.objects.push(Box::new(red_ball()));
+    let k = 2.0_f64.sqrt() / 2.0;
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -3.0), trtc::vector(0.0, -k, k));
+    let xs = trtc::Intersections {
+        intersections: vec![trtc::Intersection::new(
+            2.0_f64.sqrt(),
+            w.objects[2].as_ref(),
+        )],
+    };
+
+    let comps = trtc::prepare_computations(&xs[0], &r, &xs);
+    let color = trtc::shade_hit(&w, &comps, 5);
+
+    assert!(
+        (color - trtc::Color::new(0.93391, 0.69643, 0.69243))
+            .r
+            .abs()
+            < 0.0001
+    );
+    assert!(
+        (color - trtc::Color::new(0.93391, 0.69643, 0.69243))
+            .g
+            .abs()
+            < 0.0001
+    );
+    assert!(
+        (color - trtc::Color::new(0.93391, 0.69643, 0.69243))
+            .b
+            .abs()
+            < 0.0001
+    );
+}
+
+#[test]
+fn test_the_refracted_color_with_a_refracted_ray() {
+    let mut w = default_world();
+    let a = w.objects[0].material_mut();
+    a.ambient = 1.0;
+    a.pattern = Some(std::rc::Rc::new(TestPattern::new()));
+    let b = w.objects[1].material_mut();
+    b.transparency = 1.0;
+    b.refractive_index = 1.5;
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, 0.1), trtc::vector(0.0, 1.0, 0.0));
+    let xs = trtc::Intersections {
+        intersections: vec![
+            trtc::Intersection::new(-0.9899, w.objects[0].as_ref()),
+            trtc::Intersection::new(-0.4899, w.objects[1].as_ref()),
+            trtc::Intersection::new(0.4899, w.objects[1].as_ref()),
+            trtc::Intersection::new(0.9899, w.objects[0].as_ref()),
+        ],
+    };
+
+    let comps = trtc::prepare_computations(&xs[2], &r, &xs);
+    let c = trtc::refracted_color(&w, &comps, 5);
+
+    assert!((c - trtc::Color::new(0.0, 0.99888, 0.04725)).r.abs() < 0.0001);
+    assert!((c - trtc::Color::new(0.0, 0.99888, 0.04725)).g.abs() < 0.0001);
+    assert!((c - trtc::Color::new(0.0, 0.99888, 0.04725)).b.abs() < 0.0001);
+}
+
+#[test]
+fn test_point_lights_evaluate_the_light_intensity_at_a_given_point() {
+    let w = default_world();
+    let light = w.lights[0].as_ref();
+    let examples = [
+        (trtc::point(0.0, 1.0001, 0.0), 1.0),
+        (trtc::point(-1.0001, 0.0, 0.0), 1.0),
+        (trtc::point(0.0, 0.0, -1.0001), 1.0),
+        (trtc::point(0.0, 0.0, 1.0001), 0.0),
+        (trtc::point(1.0001, 0.0, 0.0), 0.0),
+        (trtc::point(0.0, -1.0001, 0.0), 0.0),
+        (trtc::point(0.0, 0.0, 0.0), 0.0),
+    ];
+
+    for (p, expected) in examples {
+        assert_eq!(trtc::intensity_at(&w, light, p), expected);
+    }
+}
+
+fn square_light(usteps: usize, vsteps: usize) -> trtc::RectangleLight {
+    let mut light = trtc::RectangleLight::new(
+        trtc::point(-0.5, -0.5, -5.0),
+        trtc::vector(1.0, 0.0, 0.0),
+        usteps,
+        trtc::vector(0.0, 1.0, 0.0),
+        vsteps,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+    light.jitter = false;
+    light
+}
+
+#[test]
+fn test_the_area_light_intensity_function() {
+    let w = default_world();
+    let light = square_light(2, 2);
+    let examples = [
+        (trtc::point(0.0, 0.0, 2.0), 0.0),
+        (trtc::point(1.0, -1.0, 2.0), 0.25),
+        (trtc::point(1.5, 0.0, 2.0), 0.5),
+        (trtc::point(1.25, 1.25, 3.0), 0.75),
+        (trtc::point(0.0, 0.0, -2.0), 1.0),
+    ];
+
+    for (p, expected) in examples {
+        assert_eq!(trtc::intensity_at(&w, &light, p), expected);
+    }
+}
+
+#[test]
+fn test_a_jittered_area_light_casts_a_penumbra() {
+    let w = default_world();
+    let mut light = square_light(4, 4);
+    light.jitter = true;
+
+    let umbra = trtc::intensity_at(&w, &light, trtc::point(0.0, 0.0, 2.0));
+    let penumbra = trtc::intensity_at(&w, &light, trtc::point(1.5, 0.0, 2.0));
+    let lit = trtc::intensity_at(&w, &light, trtc::point(0.0, 0.0, -2.0));
+
+    assert_eq!(umbra, 0.0);
+    assert!(penumbra > 0.0 && penumbra < 1.0);
+    assert_eq!(lit, 1.0);
+}
+
+#[test]
+fn test_a_sphere_light_casts_a_penumbra() {
+    let w = default_world();
+    let light = trtc::SphereLight::new(
+        trtc::point(0.0, 0.0, -5.0),
+        1.0,
+        4,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    let umbra = trtc::intensity_at(&w, &light, trtc::point(0.0, 0.0, 2.0));
+    let penumbra = trtc::intensity_at(&w, &light, trtc::point(1.2, 0.0, 2.0));
+    let lit = trtc::intensity_at(&w, &light, trtc::point(0.0, 0.0, -2.0));
+
+    assert_eq!(umbra, 0.0);
+    assert!(penumbra > 0.0 && penumbra < 1.0);
+    assert_eq!(lit, 1.0);
+}
+
+#[test]
+fn test_shade_hit_softens_shadows_from_an_area_light() {
+    let mut w = default_world();
+    w.lights = vec![Box::new(trtc::SphereLight::new(
+        trtc::point(0.0, 0.0, -5.0),
+        1.0,
+        4,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    ))];
+    let wall = trtc::Plane::new(
+        trtc::Matrix4::eye()
+            .rotate_x(std::f64::consts::PI / 2.0)
+            .translate(0.0, 0.0, 2.0),
+    );
+    w.objects.push(Box::new(wall));
+    let r = trtc::Ray::new(trtc::point(1.2, 0.0, -10.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let xs = trtc::intersect_world(&w, &r);
+    let hit = trtc::hit(&xs).unwrap();
+    let comps = trtc::prepare_computations(hit, &r, &xs);
+    let color = trtc::shade_hit(&w, &comps, 5);
+
+    assert!(color.r > 0.1 && color.r < 1.0);
+}
+
+#[test]
+fn test_shadows_from_a_directional_light() {
+    let w = default_world();
+    let light = trtc::DirectionalLight::new(
+        trtc::vector(0.0, -1.0, 0.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    );
+
+    assert_eq!(
+        trtc::intensity_at(&w, &light, trtc::point(0.0, -1.0001, 0.0)),
+        0.0
+    );
+    assert_eq!(
+        trtc::intensity_at(&w, &light, trtc::point(0.0, -100.0, 0.0)),
+        0.0
+    );
+    assert_eq!(
+        trtc::intensity_at(&w, &light, trtc::point(2.0, -100.0, 0.0)),
+        1.0
+    );
+}
+
+#[test]
+fn test_shading_outside_a_spotlight_cone_is_dark() {
+    let mut w = default_world();
+    w.lights = vec![Box::new(trtc::SpotLight::new(
+        trtc::point(0.0, 0.0, -10.0),
+        trtc::vector(0.0, 1.0, 0.0),
+        std::f64::consts::PI / 8.0,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    ))];
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    let c = trtc::color_at(&w, &r, 5);
+
+    assert_eq!(c, trtc::Color::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_shading_inside_a_spotlight_cone_matches_a_point_light() {
+    let mut w = default_world();
+    w.lights = vec![Box::new(trtc::SpotLight::new(
+        trtc::point(0.0, 0.0, -10.0),
+        trtc::vector(0.0, 0.0, 1.0),
+        std::f64::consts::PI / 4.0,
+        trtc::Color::new(1.0, 1.0, 1.0),
+    ))];
+    let mut reference = default_world();
+    reference.lights = vec![Box::new(trtc::PointLight::new(
+        trtc::point(0.0, 0.0, -10.0),
+        trtc::Color::new(1.0, 1.0, 1.0),
+    ))];
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+
+    assert_eq!(trtc::color_at(&w, &r, 5), trtc::color_at(&reference, &r, 5));
+}
+
+#[derive(Debug)]
+struct CountingLight {
+    light: trtc::RectangleLight,
+    calls: std::rc::Rc<std::cell::Cell<usize>>,
+}
+
+impl trtc::Light for CountingLight {
+    fn ambient_intensity(&self, point: trtc::Tuple4) -> trtc::Color {
+        self.light.ambient_intensity(point)
+    }
+
+    fn samples(&self, point: trtc::Tuple4) -> Vec<trtc::LightSample> {
+        self.calls.set(self.calls.get() + 1);
+        self.light.samples(point)
+    }
+}
+
+#[test]
+fn test_shade_hit_samples_each_light_once() {
+    let calls = std::rc::Rc::new(std::cell::Cell::new(0));
+    let mut w = default_world();
+    w.lights = vec![Box::new(CountingLight {
+        light: square_light(4, 4),
+        calls: calls.clone(),
+    })];
+    let r = trtc::Ray::new(trtc::point(0.0, 0.0, -5.0), trtc::vector(0.0, 0.0, 1.0));
+    let i = trtc::Intersection::new(4.0, w.objects[0].as_ref());
+    let xs = trtc::Intersections {
+        intersections: vec![i],
+    };
+
+    let comps = trtc::prepare_computations(&i, &r, &xs);
+    trtc::shade_hit(&w, &comps, 0);
+
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn test_visible_samples_darken_occluded_samples() {
+    let w = default_world();
+    let light = square_light(2, 2);
+
+    let lit = trtc::visible_samples(&w, &light, trtc::point(0.0, 0.0, -2.0));
+    let shadowed = trtc::visible_samples(&w, &light, trtc::point(0.0, 0.0, 2.0));
+
+    assert_eq!(lit.len(), 4);
+    assert!(lit.iter().all(|s| s.intensity == light.intensity));
+    assert_eq!(shadowed.len(), 4);
+    assert!(
+        shadowed
+            .iter()
+            .all(|s| s.intensity == trtc::Color::new(0.0, 0.0, 0.0))
+    );
+}